@@ -0,0 +1,396 @@
+//! 把其他格式的节假日数据转换为 riqi 的 HolidayOfYearList JSON
+
+use crate::validate_holiday_file;
+use chrono::{Duration, NaiveDate};
+use clap::ValueEnum;
+use riqi::modal::{Holiday, HolidayOfYearList, PrimaryType};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ImportFormat {
+    /// Nager.Date 的 PublicHolidays JSON
+    Nager,
+    /// iCalendar (.ics) 节假日日历
+    Ics,
+    /// date,name,type 三列的 CSV
+    Csv,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ImportArgs {
+    /// file to convert
+    pub input: PathBuf,
+
+    #[arg(long, value_enum)]
+    pub format: ImportFormat,
+
+    #[arg(long)]
+    pub language: String,
+
+    #[arg(long)]
+    pub country: String,
+
+    /// type of entries without one (ICS events without a known category, CSV rows without type)
+    #[arg(long, default_value = "National holiday")]
+    pub default_type: String,
+
+    /// only import this year
+    #[arg(long)]
+    pub year: Option<i32>,
+}
+
+/// 解析类型名称，支持 riqi 的类型名称和常见的别名
+pub fn parse_type(name: &str) -> Option<PrimaryType> {
+    let name = name.trim();
+    if let Ok(primary_type) =
+        serde_json::from_value::<PrimaryType>(serde_json::Value::String(name.to_string()))
+    {
+        if primary_type.is_known() {
+            return Some(primary_type);
+        }
+    }
+    match name.to_lowercase().as_str() {
+        "public" | "national" | "public holiday" => Some(PrimaryType::NationalHoliday),
+        "bank" | "common" | "bank holiday" => Some(PrimaryType::CommonHoliday),
+        "substitute" | "observed" => Some(PrimaryType::SubstituteHoliday),
+        "workday" | "working day" | "make-up workday" => Some(PrimaryType::WorkingDayOnWeekend),
+        "observance" | "optional" | "school" | "authorities" => Some(PrimaryType::Observance),
+        "regional" => Some(PrimaryType::RegionalHoliday),
+        "half day" | "half-day" => Some(PrimaryType::HalfDayHoliday),
+        "season" => Some(PrimaryType::Season),
+        _ => None,
+    }
+}
+
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map_err(|err| format!("invalid date \"{}\": {}", date, err))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NagerHoliday {
+    date: String,
+    local_name: String,
+    name: String,
+    #[serde(default = "default_global")]
+    global: bool,
+    counties: Option<Vec<String>>,
+    #[serde(default)]
+    types: Vec<String>,
+}
+
+fn default_global() -> bool {
+    true
+}
+
+/// Nager.Date：非全国性且有 counties 的为地区性节日，其余按 types 中的第一个已知类型
+pub fn import_nager(content: &str, language: &str) -> Result<Vec<Holiday>, String> {
+    let nager_holidays: Vec<NagerHoliday> =
+        serde_json::from_str(content).map_err(|err| err.to_string())?;
+    nager_holidays
+        .into_iter()
+        .map(|holiday| {
+            let date = parse_date(&holiday.date)?;
+            // name 为英文名称，localName 为当地语言名称
+            let name = if language == "en" {
+                &holiday.name
+            } else {
+                &holiday.local_name
+            };
+            let (primary_type, subdivisions) = match holiday.counties {
+                Some(counties) if !holiday.global => (PrimaryType::RegionalHoliday, Some(counties)),
+                _ => (
+                    holiday
+                        .types
+                        .iter()
+                        .find_map(|t| parse_type(t))
+                        .unwrap_or(PrimaryType::NationalHoliday),
+                    None,
+                ),
+            };
+            Ok(Holiday::new(name, date, primary_type, subdivisions))
+        })
+        .collect()
+}
+
+/// 展开 ICS 的折行：以空格或制表符开头的行接在上一行后面
+fn unfold_ics_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for line in content.lines() {
+        match line.strip_prefix([' ', '\t']) {
+            Some(rest) if !lines.is_empty() => lines.last_mut().unwrap().push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn unescape_ics_text(text: &str) -> String {
+    text.replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+/// DATE (20260101) 或 DATE-TIME (20260101T000000Z) 的日期部分
+fn parse_ics_date(value: &str) -> Result<NaiveDate, String> {
+    value
+        .get(0..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .ok_or_else(|| format!("invalid ICS date \"{}\"", value))
+}
+
+/// ICS：每个 VEVENT 为一个节日，全天事件的 DTEND 不包含在内，多天的事件展开为每一天；
+/// CATEGORIES 中第一个已知类型作为节日类型，不支持 RRULE
+pub fn import_ics(content: &str, default_type: &PrimaryType) -> Result<Vec<Holiday>, String> {
+    let mut holidays = vec![];
+    let mut event: Option<BTreeMap<String, (String, String)>> = None;
+    for line in unfold_ics_lines(content) {
+        match line.trim_end() {
+            "BEGIN:VEVENT" => event = Some(BTreeMap::new()),
+            "END:VEVENT" => {
+                let Some(properties) = event.take() else {
+                    continue;
+                };
+                let summary = properties
+                    .get("SUMMARY")
+                    .map(|(_, value)| unescape_ics_text(value))
+                    .ok_or("VEVENT without SUMMARY")?;
+                let (_, start_value) = properties.get("DTSTART").ok_or("VEVENT without DTSTART")?;
+                let start = parse_ics_date(start_value)?;
+                let end = match properties.get("DTEND") {
+                    Some((params, value)) if params.contains("VALUE=DATE") || value.len() == 8 => {
+                        parse_ics_date(value)?
+                    }
+                    _ => start + Duration::days(1),
+                };
+                let primary_type = properties
+                    .get("CATEGORIES")
+                    .and_then(|(_, value)| value.split(',').find_map(parse_type))
+                    .unwrap_or_else(|| default_type.clone());
+                let mut date = start;
+                while date < end.max(start + Duration::days(1)) {
+                    holidays.push(Holiday::new(&summary, date, primary_type.clone(), None));
+                    date += Duration::days(1);
+                }
+            }
+            line => {
+                if let (Some(properties), Some((key, value))) =
+                    (event.as_mut(), line.split_once(':'))
+                {
+                    let (name, params) = key.split_once(';').unwrap_or((key, ""));
+                    properties.insert(
+                        name.to_uppercase(),
+                        (params.to_uppercase(), value.to_string()),
+                    );
+                }
+            }
+        }
+    }
+    Ok(holidays)
+}
+
+/// 按逗号拆分一行 CSV，支持双引号包裹的字段
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields.into_iter().map(|f| f.trim().to_string()).collect()
+}
+
+/// CSV：每行 date,name,type，可以有 date,name,type 表头，# 开头的行为注释
+pub fn import_csv(content: &str, default_type: &PrimaryType) -> Result<Vec<Holiday>, String> {
+    let mut holidays = vec![];
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields = split_csv_line(line);
+        if index == 0 && fields[0].eq_ignore_ascii_case("date") {
+            continue;
+        }
+        let [date, name, rest @ ..] = fields.as_slice() else {
+            return Err(format!("line {}: expected date,name,type", index + 1));
+        };
+        let primary_type = match rest.first().filter(|t| !t.is_empty()) {
+            Some(type_name) => parse_type(type_name)
+                .ok_or_else(|| format!("line {}: unknown type \"{}\"", index + 1, type_name))?,
+            None => default_type.clone(),
+        };
+        let date = parse_date(date).map_err(|err| format!("line {}: {}", index + 1, err))?;
+        holidays.push(Holiday::new(name, date, primary_type, None));
+    }
+    Ok(holidays)
+}
+
+/// 按年份分组，组内按日期排序并去掉同一天同名的重复节日
+pub fn group_by_year(holidays: Vec<Holiday>) -> BTreeMap<i32, Vec<Holiday>> {
+    let mut years: BTreeMap<i32, Vec<Holiday>> = BTreeMap::new();
+    for holiday in holidays {
+        years
+            .entry(holiday.date.datetime.year)
+            .or_default()
+            .push(holiday);
+    }
+    for holidays in years.values_mut() {
+        holidays.sort_by(|a, b| a.date.iso.cmp(&b.date.iso));
+        let mut seen = HashSet::new();
+        holidays.retain(|holiday| seen.insert((holiday.date.iso.clone(), holiday.name.clone())));
+    }
+    years
+}
+
+/// 转换并写入 {dir}/{year}/{language}_{country}.json，已存在的文件 version 加一
+pub fn run_import(args: &ImportArgs, dir: &Path) -> Result<(), String> {
+    let content = fs::read_to_string(&args.input)
+        .map_err(|err| format!("{}: {}", args.input.display(), err))?;
+    let default_type = parse_type(&args.default_type)
+        .ok_or_else(|| format!("unknown type \"{}\"", args.default_type))?;
+    let holidays = match args.format {
+        ImportFormat::Nager => import_nager(&content, &args.language)?,
+        ImportFormat::Ics => import_ics(&content, &default_type)?,
+        ImportFormat::Csv => import_csv(&content, &default_type)?,
+    };
+
+    for (year, holidays) in group_by_year(holidays) {
+        if args.year.is_some_and(|only_year| only_year != year) {
+            continue;
+        }
+        let path = dir
+            .join(year.to_string())
+            .join(format!("{}_{}.json", args.language, args.country));
+        let version = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<HolidayOfYearList>(&content).ok())
+            .map_or(1, |old| old.version + 1);
+        let count = holidays.len();
+        let list = HolidayOfYearList { version, holidays };
+        let json = serde_json::to_string_pretty(&list).map_err(|err| err.to_string())? + "\n";
+        let report = validate_holiday_file(year, &json);
+        if !report.errors.is_empty() {
+            return Err(format!("{}: {}", path.display(), report.errors.join("; ")));
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+        fs::write(&path, json).map_err(|err| format!("{}: {}", path.display(), err))?;
+        println!(
+            "{}: {} holidays (version {})",
+            path.display(),
+            count,
+            version
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_nager() {
+        let content = r#"[
+            {"date": "2026-01-01", "localName": "Neujahr", "name": "New Year's Day",
+             "countryCode": "DE", "global": true, "counties": null, "types": ["Public"]},
+            {"date": "2026-01-06", "localName": "Heilige Drei Könige", "name": "Epiphany",
+             "countryCode": "DE", "global": false, "counties": ["DE-BW", "DE-BY"], "types": ["Public"]},
+            {"date": "2026-12-24", "localName": "Heiligabend", "name": "Christmas Eve",
+             "countryCode": "DE", "global": true, "counties": null, "types": ["Bank", "Optional"]}
+        ]"#;
+        let holidays = import_nager(content, "de").unwrap();
+        assert_eq!(holidays[0].name, "Neujahr");
+        assert!(matches!(
+            holidays[0].primary_type,
+            PrimaryType::NationalHoliday
+        ));
+        assert!(matches!(
+            holidays[1].primary_type,
+            PrimaryType::RegionalHoliday
+        ));
+        assert_eq!(holidays[1].subdivisions.as_ref().unwrap().len(), 2);
+        assert!(matches!(
+            holidays[2].primary_type,
+            PrimaryType::CommonHoliday
+        ));
+        assert_eq!(
+            import_nager(content, "en").unwrap()[0].name,
+            "New Year's Day"
+        );
+    }
+
+    #[test]
+    fn test_import_ics() {
+        let content = "BEGIN:VCALENDAR\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20261001\r\n\
+            DTEND;VALUE=DATE:20261004\r\n\
+            SUMMARY:National Day\\, Golden\r\n  Week\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART:20261010T000000Z\r\n\
+            SUMMARY:Make-up Workday\r\n\
+            CATEGORIES:Holiday,Workday\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+        let holidays = import_ics(content, &PrimaryType::NationalHoliday).unwrap();
+        let dates: Vec<&str> = holidays.iter().map(|h| h.date.iso.as_str()).collect();
+        assert_eq!(
+            dates,
+            vec!["2026-10-01", "2026-10-02", "2026-10-03", "2026-10-10"]
+        );
+        assert_eq!(holidays[0].name, "National Day, Golden Week");
+        assert!(matches!(
+            holidays[3].primary_type,
+            PrimaryType::WorkingDayOnWeekend
+        ));
+    }
+
+    #[test]
+    fn test_import_csv() {
+        let content = "date,name,type\n\
+            # 公司数据\n\
+            2026-05-01,\"Labour Day, International\",public\n\
+            2026-05-04,Youth Day,\n\
+            2025-12-31,New Year's Eve,Half day holiday\n";
+        let holidays = import_csv(content, &PrimaryType::Observance).unwrap();
+        assert_eq!(holidays[0].name, "Labour Day, International");
+        assert!(matches!(
+            holidays[0].primary_type,
+            PrimaryType::NationalHoliday
+        ));
+        assert!(matches!(holidays[1].primary_type, PrimaryType::Observance));
+
+        let years = group_by_year(holidays);
+        assert_eq!(years.keys().copied().collect::<Vec<_>>(), vec![2025, 2026]);
+        let content = serde_json::to_string(&HolidayOfYearList {
+            version: 1,
+            holidays: years[&2026].clone(),
+        })
+        .unwrap();
+        let report = validate_holiday_file(2026, &content);
+        assert!(report.errors.is_empty());
+        assert_eq!(report.version, 1);
+
+        assert!(import_csv("2026-05-01,Labour Day,unknown", &PrimaryType::Observance).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+//! 节假日数据维护工具：校验 resources/holidays 下的所有文件，并重新生成带校验和的 meta.json
+//!
+//! cargo run -p riqi-data             校验并生成 meta.json
+//! cargo run -p riqi-data -- --check  只校验，并检查 meta.json 是否最新
+//! cargo run -p riqi-data -- import --format csv --language en --country us holidays.csv
+
+mod import;
+
+use chrono::{Datelike, NaiveDate, Utc};
+use clap::{Parser, Subcommand};
+use import::{run_import, ImportArgs};
+use riqi::modal::Holiday;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Debug, Parser)]
+#[command(about = "Validate holiday data files and regenerate meta.json")]
+struct Args {
+    /// holiday data directory
+    #[arg(long, default_value = "resources/holidays")]
+    dir: PathBuf,
+
+    /// only validate, fail if meta.json is out of date instead of writing it
+    #[arg(long)]
+    check: bool,
+
+    #[command(subcommand)]
+    command: Option<DataCommand>,
+}
+
+#[derive(Debug, Subcommand)]
+enum DataCommand {
+    /// convert Nager.Date JSON, ICS or CSV holidays into riqi's format
+    Import(ImportArgs),
+}
+
+#[derive(Debug, Deserialize)]
+struct RawHolidayFile {
+    version: i32,
+    holidays: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MetaFile {
+    version: i32,
+    sha256: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Meta {
+    files: BTreeMap<String, MetaFile>,
+    last_updated: String,
+}
+
+/// 一个年份文件的校验结果，errors 不为空时校验失败
+#[derive(Debug, Default, PartialEq)]
+struct FileReport {
+    version: i32,
+    errors: Vec<String>,
+    /// 同一天有多个节日，不算错误，但需要确认不是重复录入
+    warnings: Vec<String>,
+}
+
+/// 校验一个年份文件的内容
+fn validate_holiday_file(year: i32, content: &str) -> FileReport {
+    let file: RawHolidayFile = match serde_json::from_str(content) {
+        Ok(file) => file,
+        Err(err) => {
+            return FileReport {
+                errors: vec![err.to_string()],
+                ..FileReport::default()
+            }
+        }
+    };
+    let mut report = FileReport {
+        version: file.version,
+        ..FileReport::default()
+    };
+    let mut names_of_date: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for (index, value) in file.holidays.into_iter().enumerate() {
+        let holiday: Holiday = match serde_json::from_value(value) {
+            Ok(holiday) => holiday,
+            Err(err) => {
+                report.errors.push(format!("holidays[{}]: {}", index, err));
+                continue;
+            }
+        };
+        let position = format!("holidays[{}] {} {}", index, holiday.date.iso, holiday.name);
+
+        if !holiday.primary_type.is_known() {
+            report.errors.push(format!(
+                "{}: unknown primary_type \"{}\"",
+                position,
+                holiday.primary_type.name()
+            ));
+        }
+
+        let datetime = &holiday.date.datetime;
+        match NaiveDate::parse_from_str(&holiday.date.iso, "%Y-%m-%d") {
+            Ok(date) => {
+                if (date.year(), date.month() as i32, date.day() as i32)
+                    != (datetime.year, datetime.month, datetime.day)
+                {
+                    report.errors.push(format!(
+                        "{}: iso disagrees with datetime {}-{}-{}",
+                        position, datetime.year, datetime.month, datetime.day
+                    ));
+                }
+                if date.year() != year {
+                    report
+                        .errors
+                        .push(format!("{}: date is outside of {}", position, year));
+                }
+            }
+            Err(err) => report
+                .errors
+                .push(format!("{}: invalid iso date: {}", position, err)),
+        }
+
+        let names = names_of_date.entry(holiday.date.iso.clone()).or_default();
+        if names.contains(&holiday.name) {
+            report
+                .errors
+                .push(format!("{}: duplicate holiday", position));
+        }
+        names.push(holiday.name);
+    }
+
+    // 同一天的不同节日 (如 2025-10-06 的国庆假和中秋) 是合法的，只提示
+    for (date, names) in names_of_date {
+        if names.len() > 1 {
+            report
+                .warnings
+                .push(format!("{}: duplicate date ({})", date, names.join(", ")));
+        }
+    }
+    report
+}
+
+/// 数据目录下的所有 (year_lang_country, year, path)，按 key 排序
+fn list_holiday_files(dir: &Path) -> Vec<(String, i32, PathBuf)> {
+    let mut files = vec![];
+    for year_dir in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let Some(year) = year_dir
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<i32>().ok())
+        else {
+            continue;
+        };
+        for file in fs::read_dir(year_dir.path())
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            let path = file.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                files.push((format!("{}_{}", year, stem), year, path.clone()));
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if let Some(DataCommand::Import(import_args)) = &args.command {
+        if let Err(err) = run_import(import_args, &args.dir) {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+        println!("run riqi-data to regenerate meta.json");
+        return ExitCode::SUCCESS;
+    }
+    let mut meta_files = BTreeMap::new();
+    let mut failed = false;
+
+    for (key, year, path) in list_holiday_files(&args.dir) {
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) => {
+                eprintln!("{}: {}", path.display(), err);
+                failed = true;
+                continue;
+            }
+        };
+        let report = validate_holiday_file(year, &content);
+        for warning in &report.warnings {
+            eprintln!("{}: warning: {}", path.display(), warning);
+        }
+        if !report.errors.is_empty() {
+            for error in &report.errors {
+                eprintln!("{}: {}", path.display(), error);
+            }
+            failed = true;
+            continue;
+        }
+        let sha256 = hex::encode(Sha256::digest(content.as_bytes()));
+        meta_files.insert(
+            key,
+            MetaFile {
+                version: report.version,
+                sha256: Some(sha256),
+            },
+        );
+    }
+    if failed {
+        return ExitCode::FAILURE;
+    }
+    println!("{} files are valid", meta_files.len());
+
+    let meta_path = args.dir.join("meta.json");
+    let old_meta: Option<Meta> = fs::read_to_string(&meta_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok());
+    if old_meta.is_some_and(|meta| meta.files == meta_files) {
+        println!("meta.json is up to date");
+        return ExitCode::SUCCESS;
+    }
+    if args.check {
+        eprintln!("meta.json is out of date, run riqi-data to regenerate it");
+        return ExitCode::FAILURE;
+    }
+
+    let meta = Meta {
+        files: meta_files,
+        last_updated: Utc::now().to_rfc3339(),
+    };
+    let content = serde_json::to_string_pretty(&meta).expect("serialize meta") + "\n";
+    if let Err(err) = fs::write(&meta_path, content) {
+        eprintln!("{}: {}", meta_path.display(), err);
+        return ExitCode::FAILURE;
+    }
+    println!("{} regenerated", meta_path.display());
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holiday_json(iso: &str, year: i32, month: u32, day: u32, primary_type: &str) -> String {
+        format!(
+            r#"{{"name": "Test", "type": ["National holiday"], "primary_type": "{}",
+                "date": {{"iso": "{}", "datetime": {{"year": {}, "month": {}, "day": {}}}}}}}"#,
+            primary_type, iso, year, month, day
+        )
+    }
+
+    #[test]
+    fn test_validate_holiday_file() {
+        let valid = holiday_json("2026-01-01", 2026, 1, 1, "National holiday");
+        let content = format!(r#"{{"version": 3, "holidays": [{}]}}"#, valid);
+        assert_eq!(
+            validate_holiday_file(2026, &content),
+            FileReport {
+                version: 3,
+                ..FileReport::default()
+            }
+        );
+
+        let content = format!(
+            r#"{{"version": 1, "holidays": [{}, {}, {}, {}, {}]}}"#,
+            valid,
+            valid,
+            holiday_json("2026-01-02", 2026, 1, 3, "National holiday"),
+            holiday_json("2025-12-31", 2025, 12, 31, "National holiday"),
+            holiday_json("2026-05-01", 2026, 5, 1, "Bank holiday"),
+        );
+        let errors = validate_holiday_file(2026, &content).errors;
+        assert_eq!(errors.len(), 4);
+        assert!(errors[0].contains("duplicate holiday"));
+        assert!(errors[1].contains("iso disagrees"));
+        assert!(errors[2].contains("outside of 2026"));
+        assert!(errors[3].contains("unknown primary_type"));
+
+        assert!(!validate_holiday_file(2026, r#"{"holidays": []}"#)
+            .errors
+            .is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_dates_are_reported() {
+        let content = format!(
+            r#"{{"version": 1, "holidays": [{}, {}]}}"#,
+            holiday_json("2026-01-01", 2026, 1, 1, "National holiday"),
+            holiday_json("2026-01-01", 2026, 1, 1, "Observance").replace("Test", "Other"),
+        );
+        let report = validate_holiday_file(2026, &content);
+        assert!(report.errors.is_empty());
+        assert_eq!(
+            report.warnings,
+            vec!["2026-01-01: duplicate date (Test, Other)"]
+        );
+    }
+
+    #[test]
+    fn test_bundled_holiday_files_are_valid() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../resources/holidays");
+        let files = list_holiday_files(&dir);
+        assert!(!files.is_empty());
+        for (key, year, path) in files {
+            let content = fs::read_to_string(path).unwrap();
+            let report = validate_holiday_file(year, &content);
+            assert!(report.errors.is_empty(), "{}: {:?}", key, report.errors);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::config::cli::DayQuery;
+use crate::config::model::AppConfig;
+use crate::holiday::cache::{load_cache_validators, touch_cache_file};
+use crate::holiday::http::HttpClient;
+use crate::holiday::manager::{
+    fetch_holidays_from_sources, load_local_holidays, save_holidays_file, FetchHolidays,
+};
+use crate::holiday::overrides::load_holiday_overrides;
+use crate::holiday::utils::{
+    get_holiday_cache_file_path, get_holiday_state, get_ylc_code, DayState,
+};
+use chrono::{Datelike, Local, NaiveDate};
+use color_eyre::Result;
+
+/// 回答为“是”
+const EXIT_YES: i32 = 0;
+/// 回答为“否”
+const EXIT_NO: i32 = 1;
+/// 没有这一年的节假日数据，无法判断
+const EXIT_UNKNOWN: i32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayQuestion {
+    IsWorkday,
+    IsHoliday,
+}
+
+impl DayQuestion {
+    /// 半天假算工作日；没有节假日数据时 state 为 None
+    fn exit_code(self, state: Option<DayState>) -> i32 {
+        match (self, state) {
+            (_, None) => EXIT_UNKNOWN,
+            (DayQuestion::IsWorkday, Some(state)) if !state.is_rest() => EXIT_YES,
+            (DayQuestion::IsHoliday, Some(state)) if state.is_rest() => EXIT_YES,
+            _ => EXIT_NO,
+        }
+    }
+}
+
+/// 输出 yes、no 或 unknown，并以对应的退出码结束进程
+pub async fn run_day_query(
+    question: DayQuestion,
+    query: DayQuery,
+    app_config: &AppConfig,
+) -> Result<()> {
+    let mut app_config = app_config.clone();
+    if let Some(country) = query.country {
+        app_config.country = country;
+    }
+    if let Some(language) = query.language {
+        app_config.language = language;
+    }
+    let date = query.date.unwrap_or_else(|| Local::now().date_naive());
+    if query.fetch {
+        fetch_year(&date.year().to_string(), &app_config).await;
+    }
+
+    let state = local_day_state(date, &app_config);
+    let code = question.exit_code(state);
+    match code {
+        EXIT_YES => println!("yes"),
+        EXIT_NO => println!("no"),
+        _ => {
+            println!("unknown");
+            eprintln!(
+                "no holiday data for {}, try --fetch",
+                get_ylc_code(
+                    &date.year().to_string(),
+                    &app_config.language,
+                    &app_config.country
+                )
+            );
+        }
+    }
+    std::process::exit(code)
+}
+
+/// 只使用缓存或内置数据，规则生成的数据不包含调休，不用来判断
+fn local_day_state(date: NaiveDate, app_config: &AppConfig) -> Option<DayState> {
+    let year = date.year().to_string();
+    let list = load_local_holidays(&year, &app_config.language, &app_config.country)?;
+    let ylc_key = get_ylc_code(&year, &app_config.language, &app_config.country);
+    let holiday_map = load_holiday_overrides()
+        .apply(&ylc_key, &app_config.country, list)
+        .to_holiday_map();
+    let holidays = holiday_map
+        .get(&date.format("%Y-%m-%d").to_string())
+        .cloned();
+    let (state, _) = get_holiday_state(
+        &holidays,
+        date.weekday().num_days_from_sunday() as u16,
+        app_config.region.as_deref(),
+    );
+    Some(state)
+}
+
+/// 下载这一年的节假日数据并写入缓存，失败时继续使用本地数据
+async fn fetch_year(year: &str, app_config: &AppConfig) {
+    let (language, country) = (&app_config.language, &app_config.country);
+    let http_client = HttpClient::from_config(app_config);
+    let cache_path = get_holiday_cache_file_path(year, language, country);
+    // 只有缓存文件存在时，条件请求头才有意义
+    let cached = cache_path
+        .as_deref()
+        .filter(|path| path.exists())
+        .and_then(load_cache_validators);
+    match fetch_holidays_from_sources(
+        &http_client,
+        year,
+        language,
+        country,
+        &app_config.sources,
+        cached.as_ref(),
+        None,
+    )
+    .await
+    {
+        Some(FetchHolidays::Downloaded {
+            content,
+            source,
+            validators,
+            ..
+        }) => {
+            if let Err(err) = save_holidays_file(
+                year,
+                language,
+                country,
+                source,
+                &validators,
+                content.as_bytes(),
+            )
+            .await
+            {
+                eprintln!("save holiday data failed: {}", err);
+            }
+        }
+        Some(FetchHolidays::NotModified) => {
+            if let Some(path) = &cache_path {
+                let _ = touch_cache_file(path);
+            }
+        }
+        Some(FetchHolidays::NotFound) => eprintln!(
+            "{}: not published by any source yet, using local data",
+            get_ylc_code(year, language, country)
+        ),
+        None => eprintln!(
+            "{}: not available from any source, using local data",
+            get_ylc_code(year, language, country)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(DayQuestion::IsWorkday.exit_code(Some(DayState::Work)), 0);
+        assert_eq!(DayQuestion::IsWorkday.exit_code(Some(DayState::HalfDay)), 0);
+        assert_eq!(DayQuestion::IsWorkday.exit_code(Some(DayState::Rest)), 1);
+        assert_eq!(DayQuestion::IsHoliday.exit_code(Some(DayState::Rest)), 0);
+        assert_eq!(DayQuestion::IsHoliday.exit_code(Some(DayState::HalfDay)), 1);
+        assert_eq!(DayQuestion::IsWorkday.exit_code(None), 2);
+        assert_eq!(DayQuestion::IsHoliday.exit_code(None), 2);
+    }
+}
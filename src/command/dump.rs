@@ -0,0 +1,220 @@
+use crate::command::{offline_holiday_map, offline_workday_calendar, warn_years_without_data};
+use crate::config::cli::DumpFormat;
+use crate::config::model::AppConfig;
+use crate::data::calendar::{get_iso_data_str, CalendarDay};
+use crate::holiday::modal::Holiday;
+use crate::holiday::utils::{get_holiday_state, get_ylc_code, DayState};
+use chrono::{Datelike, NaiveDate};
+use color_eyre::eyre::bail;
+use color_eyre::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+
+const CSV_HEADER: &str =
+    "date,weekday,lunar_month,lunar_day,lunar_leap,state,holidays,holiday_types";
+
+/// 导出的一天：CalendarDay 的数据加上按 get_holiday_state 计算的上班/放假状态。
+/// 没有缓存或内置数据的年份，规则生成的数据不包含调休，state 为 null
+#[derive(Debug, Serialize)]
+struct DayRecord {
+    date: String,
+    weekday: String,
+    #[serde(flatten)]
+    day: CalendarDay,
+    lunar_leap: bool,
+    state: Option<DayState>,
+}
+
+impl DayRecord {
+    fn new(
+        date: NaiveDate,
+        holidays: Option<&Vec<Holiday>>,
+        region: Option<&str>,
+        has_data: bool,
+    ) -> Self {
+        let day = CalendarDay::new(
+            date.year() as u32,
+            date.month(),
+            date.day(),
+            date.weekday().num_days_from_sunday(),
+            false,
+            true,
+            date,
+            holidays,
+        );
+        let state =
+            has_data.then(|| get_holiday_state(&day.holidays, day.day_of_week as u16, region).0);
+        DayRecord {
+            date: date.format("%Y-%m-%d").to_string(),
+            weekday: date.weekday().to_string(),
+            lunar_leap: day.is_leap_lunar_month(),
+            day,
+            state,
+        }
+    }
+
+    /// 多个节日的名称和类型分别用 ; 连接
+    fn to_csv_row(&self) -> String {
+        let holidays = self.day.holidays.iter().flatten();
+        let names = holidays
+            .clone()
+            .map(|holiday| holiday.name.clone())
+            .collect::<Vec<_>>()
+            .join(";");
+        let types = holidays
+            .map(|holiday| holiday.primary_type.name())
+            .collect::<Vec<_>>()
+            .join(";");
+        // 没有数据时为空
+        let state = serde_json::to_value(self.state)
+            .ok()
+            .and_then(|value| value.as_str().map(String::from))
+            .unwrap_or_default();
+        [
+            self.date.clone(),
+            self.weekday.clone(),
+            self.day.lunar_month.abs().to_string(),
+            self.day.lunar_day.to_string(),
+            self.lunar_leap.to_string(),
+            state,
+            names,
+            types,
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+pub fn run_dump(
+    from: NaiveDate,
+    to: NaiveDate,
+    format: DumpFormat,
+    app_config: &AppConfig,
+) -> Result<()> {
+    if from > to {
+        bail!("--from {} is after --to {}", from, to);
+    }
+    let holiday_map = offline_holiday_map(app_config, from.year()..=to.year());
+    // 与 workdays 一样，上班/放假状态只根据缓存或内置数据判断
+    let calendar = offline_workday_calendar(app_config);
+    // 按年份转换成以日期为 key 的节假日
+    let date_maps: HashMap<i32, HashMap<String, Vec<Holiday>>> = (from.year()..=to.year())
+        .filter_map(|year| {
+            let ylc_key =
+                get_ylc_code(&year.to_string(), &app_config.language, &app_config.country);
+            Some((year, holiday_map.get(&ylc_key)?.to_holiday_map()))
+        })
+        .collect();
+    let records = from.iter_days().take_while(|date| *date <= to).map(|date| {
+        let holidays = date_maps.get(&date.year()).and_then(|date_map| {
+            date_map.get(&get_iso_data_str(
+                date.year() as u32,
+                date.month(),
+                date.day(),
+            ))
+        });
+        DayRecord::new(
+            date,
+            holidays,
+            app_config.region.as_deref(),
+            calendar.has_data(date.year()),
+        )
+    });
+
+    match format {
+        DumpFormat::Json => {
+            let records: Vec<DayRecord> = records.collect();
+            println!("{}", serde_json::to_string_pretty(&records)?);
+        }
+        DumpFormat::Csv => {
+            println!("{}", CSV_HEADER);
+            for record in records {
+                println!("{}", record.to_csv_row());
+            }
+        }
+    }
+    warn_years_without_data(&calendar, app_config);
+    Ok(())
+}
+
+/// 包含逗号、引号或换行时用引号包起来，引号写两遍
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holiday::modal::PrimaryType;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_day_record() {
+        let holidays = vec![Holiday::new(
+            "国庆节",
+            date(2026, 10, 1),
+            PrimaryType::NationalHoliday,
+            None,
+        )];
+        let record = DayRecord::new(date(2026, 10, 1), Some(&holidays), None, true);
+        assert_eq!(record.state, Some(DayState::Rest));
+        assert_eq!(
+            record.to_csv_row(),
+            "2026-10-01,Thu,8,21,false,rest,国庆节,National holiday"
+        );
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["date"], "2026-10-01");
+        assert_eq!(json["day_of_week"], 4);
+        assert_eq!(json["state"], "rest");
+        assert_eq!(json["holidays"][0]["primary_type"], "National holiday");
+        assert!(json.get("is_today").is_none());
+    }
+
+    #[test]
+    fn test_day_record_in_leap_month() {
+        // 2025-08-01 是闰六月初八
+        let record = DayRecord::new(date(2025, 8, 1), None, None, true);
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["lunar_month"], 6);
+        assert_eq!(json["lunar_day"], 8);
+        assert_eq!(json["lunar_leap"], true);
+        assert_eq!(json["state"], "work");
+    }
+
+    #[test]
+    fn test_day_record_without_data() {
+        // 规则生成的国庆节不包含调休，不能据此判断上班/放假
+        let holidays = vec![Holiday::new(
+            "国庆节",
+            date(2030, 10, 1),
+            PrimaryType::NationalHoliday,
+            None,
+        )];
+        let record = DayRecord::new(date(2030, 10, 1), Some(&holidays), None, false);
+        assert_eq!(record.state, None);
+        assert_eq!(
+            record.to_csv_row(),
+            "2030-10-01,Tue,9,5,false,,国庆节,National holiday"
+        );
+        let json = serde_json::to_value(&record).unwrap();
+        assert!(json["state"].is_null());
+        assert_eq!(json["holidays"][0]["name"], "国庆节");
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("Christmas"), "Christmas");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
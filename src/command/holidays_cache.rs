@@ -0,0 +1,169 @@
+use crate::config::cli::CacheAction;
+use crate::config::model::AppConfig;
+use crate::holiday::cache::{list_cache_entries, prune_cache};
+use crate::holiday::http::HttpClient;
+use crate::holiday::manager::{fetch_holidays_from_sources, save_holidays_file, FetchHolidays};
+use crate::holiday::utils::{get_holidays_cache_dir, parse_ylc_code};
+use chrono::{Datelike, Local};
+use color_eyre::eyre::{bail, eyre};
+use color_eyre::Result;
+
+pub async fn run_cache_action(action: CacheAction, app_config: &AppConfig) -> Result<()> {
+    let holidays_dir =
+        get_holidays_cache_dir().ok_or_else(|| eyre!("get holiday cache dir failed"))?;
+    match action {
+        CacheAction::List => {
+            println!("{:<16} {:>7}  {:<19}  SOURCE", "KEY", "VERSION", "MODIFIED");
+            for entry in list_cache_entries(&holidays_dir) {
+                let version = entry
+                    .verify()
+                    .map(|version| version.to_string())
+                    .unwrap_or_else(|_| "invalid".to_string());
+                let modified = entry
+                    .modified
+                    .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_default();
+                let source = entry.source().unwrap_or_else(|| "-".to_string());
+                println!(
+                    "{:<16} {:>7}  {:<19}  {}",
+                    entry.key, version, modified, source
+                );
+            }
+        }
+        CacheAction::Refresh { keys } => {
+            let http_client = HttpClient::from_config(app_config);
+            let keys = if keys.is_empty() {
+                list_cache_entries(&holidays_dir)
+                    .into_iter()
+                    .map(|entry| entry.key)
+                    .collect()
+            } else {
+                keys
+            };
+            let mut failed = 0;
+            for key in &keys {
+                let Some((year, language, country)) = parse_ylc_code(key) else {
+                    eprintln!("{}: invalid key, expected year_language_country", key);
+                    failed += 1;
+                    continue;
+                };
+                let year = year.to_string();
+                // 强制刷新，不发送条件请求
+                match fetch_holidays_from_sources(
+                    &http_client,
+                    &year,
+                    language,
+                    country,
+                    &app_config.sources,
+                    None,
+                    None,
+                )
+                .await
+                {
+                    Some(FetchHolidays::Downloaded {
+                        content,
+                        holidays,
+                        source,
+                        validators,
+                    }) => {
+                        save_holidays_file(
+                            &year,
+                            language,
+                            country,
+                            source,
+                            &validators,
+                            content.as_bytes(),
+                        )
+                        .await?;
+                        println!(
+                            "{}: version {} from {}",
+                            key,
+                            holidays.version,
+                            source.as_str()
+                        );
+                    }
+                    Some(FetchHolidays::NotFound) => {
+                        eprintln!("{}: not published by any source yet", key);
+                        failed += 1;
+                    }
+                    // refresh 不带条件请求头，但镜像、CDN 或缓存代理仍可能返回 304
+                    Some(FetchHolidays::NotModified) => {
+                        eprintln!("{}: not modified, the source returned no data", key);
+                        failed += 1;
+                    }
+                    None => {
+                        eprintln!("{}: not available from any source", key);
+                        failed += 1;
+                    }
+                }
+            }
+            if failed > 0 {
+                bail!("{} of {} keys failed to refresh", failed, keys.len());
+            }
+        }
+        CacheAction::Prune { before } => {
+            let before = before.unwrap_or(Local::now().year() - 1);
+            for year in prune_cache(&holidays_dir, before)? {
+                println!("removed {}", year);
+            }
+        }
+        CacheAction::Verify => {
+            let mut failed = 0;
+            for entry in list_cache_entries(&holidays_dir) {
+                match entry.verify() {
+                    Ok(version) => println!("{}: ok (version {})", entry.key, version),
+                    Err(err) => {
+                        println!("{}: {}", entry.key, err);
+                        failed += 1;
+                    }
+                }
+            }
+            if failed > 0 {
+                bail!("{} cached files failed to parse", failed);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::model::Source;
+
+    /// 不管请求头是什么都返回 304 的服务器，模拟有问题的镜像或缓存代理
+    async fn spawn_not_modified_server() -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let _ = stream.read(&mut buf).await.unwrap();
+                let response = "HTTP/1.1 304 Not Modified\r\nConnection: close\r\n\r\n";
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        format!("http://{}/", addr)
+    }
+
+    #[tokio::test]
+    async fn test_refresh_not_modified() {
+        let app_config = AppConfig {
+            sources: vec![Source::Custom(spawn_not_modified_server().await)],
+            ..AppConfig::for_test()
+        };
+        let result = run_cache_action(
+            CacheAction::Refresh {
+                keys: vec!["2026_zh_cn".to_string()],
+            },
+            &app_config,
+        )
+        .await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "1 of 1 keys failed to refresh"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+pub mod day_query;
+pub mod dump;
+pub mod holidays_cache;
+pub mod plan_leave;
+pub mod print;
+pub mod workdays;
+
+use crate::command::day_query::DayQuestion;
+use crate::config::cli::{Command, HolidaysCommand};
+use crate::config::model::AppConfig;
+use crate::holiday::manager::{load_local_holidays, load_offline_holidays};
+use crate::holiday::modal::HolidayOfYearList;
+use crate::holiday::overrides::{load_holiday_overrides, HolidayOverrides};
+use crate::holiday::utils::get_ylc_code;
+use crate::holiday::workdays::WorkdayCalendar;
+use color_eyre::Result;
+use std::collections::HashMap;
+
+/// 执行子命令，不进入 TUI
+pub async fn run_command(command: Command, app_config: &AppConfig) -> Result<()> {
+    match command {
+        Command::Holidays { command } => match command {
+            HolidaysCommand::Cache { action } => {
+                holidays_cache::run_cache_action(action, app_config).await
+            }
+        },
+        Command::Workdays { action } => workdays::run_workdays_action(action, app_config),
+        Command::PlanLeave { year, days, limit } => {
+            plan_leave::run_plan_leave(year, days, limit, app_config)
+        }
+        Command::Print {
+            month,
+            months,
+            year,
+            columns,
+            color,
+        } => print::run_print(month, months, year, columns, color, app_config),
+        Command::Dump { from, to, format } => dump::run_dump(from, to, format, app_config),
+        Command::IsWorkday { query } => {
+            day_query::run_day_query(DayQuestion::IsWorkday, query, app_config).await
+        }
+        Command::IsHoliday { query } => {
+            day_query::run_day_query(DayQuestion::IsHoliday, query, app_config).await
+        }
+    }
+}
+
+/// 使用缓存或内置的节假日数据和用户调整的工作日日历。规则推算不出调休，所以不使用规则
+/// 生成的数据，没有数据的年份只按周末计算，由调用方通过 years_without_data 提示
+pub fn offline_workday_calendar(app_config: &AppConfig) -> WorkdayCalendar<'_> {
+    let overrides = load_holiday_overrides();
+    WorkdayCalendar::new(app_config.region.clone(), move |year| {
+        let list =
+            load_local_holidays(&year.to_string(), &app_config.language, &app_config.country)?;
+        Some(apply_overrides(app_config, &overrides, year, list).to_holiday_map())
+    })
+}
+
+/// 使用本地节假日数据 (缓存、内置数据或规则) 加载多个年份，key 与 TUI 中的 holiday_map 一致。
+/// 都没有时从空列表开始合并用户的调整
+pub fn offline_holiday_map(
+    app_config: &AppConfig,
+    years: impl IntoIterator<Item = i32>,
+) -> HashMap<String, HolidayOfYearList> {
+    let overrides = load_holiday_overrides();
+    years
+        .into_iter()
+        .map(|year| {
+            let list =
+                load_offline_holidays(&year.to_string(), &app_config.language, &app_config.country)
+                    .unwrap_or(HolidayOfYearList {
+                        version: 0,
+                        holidays: vec![],
+                    });
+            let ylc_key =
+                get_ylc_code(&year.to_string(), &app_config.language, &app_config.country);
+            (ylc_key, apply_overrides(app_config, &overrides, year, list))
+        })
+        .collect()
+}
+
+/// 没有节假日数据的年份在 stderr 上提示，结果只按周末计算
+pub fn warn_years_without_data(calendar: &WorkdayCalendar, app_config: &AppConfig) {
+    for year in calendar.years_without_data() {
+        let ylc_key = get_ylc_code(&year.to_string(), &app_config.language, &app_config.country);
+        eprintln!(
+            "warning: no holiday data for {}, only weekends are counted; run `riqi holidays cache refresh {}` to download it",
+            ylc_key, ylc_key
+        );
+    }
+}
+
+fn apply_overrides(
+    app_config: &AppConfig,
+    overrides: &HolidayOverrides,
+    year: i32,
+    list: HolidayOfYearList,
+) -> HolidayOfYearList {
+    let ylc_key = get_ylc_code(&year.to_string(), &app_config.language, &app_config.country);
+    overrides.apply(&ylc_key, &app_config.country, list)
+}
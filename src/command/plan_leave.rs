@@ -0,0 +1,50 @@
+use crate::command::{offline_workday_calendar, warn_years_without_data};
+use crate::config::model::AppConfig;
+use crate::holiday::leave::plan_leave;
+use crate::holiday::utils::get_ylc_code;
+use chrono::{Datelike, Local};
+use color_eyre::eyre::bail;
+use color_eyre::Result;
+
+pub fn run_plan_leave(
+    year: Option<i32>,
+    days: usize,
+    limit: usize,
+    app_config: &AppConfig,
+) -> Result<()> {
+    if days == 0 {
+        bail!("--days must be at least 1");
+    }
+    let year = year.unwrap_or_else(|| Local::now().year());
+    let calendar = offline_workday_calendar(app_config);
+    let plans = plan_leave(&calendar, year, days, limit);
+    // 只按周末规划没有意义，没有这一年的数据时直接拒绝；跨年假期用到的相邻年份只提示
+    if calendar.years_without_data().contains(&year) {
+        let ylc_key = get_ylc_code(&year.to_string(), &app_config.language, &app_config.country);
+        bail!(
+            "no holiday data for {}, run `riqi holidays cache refresh {}` first",
+            ylc_key,
+            ylc_key
+        );
+    }
+    warn_years_without_data(&calendar, app_config);
+    if plans.is_empty() {
+        bail!("no break found in {}", year);
+    }
+    for plan in plans {
+        let leave_days = plan
+            .leave_days
+            .iter()
+            .map(|date| date.format(&app_config.output).to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "{} ~ {}  {:>2} days off  leave: {}",
+            plan.start.format(&app_config.output),
+            plan.end.format(&app_config.output),
+            plan.days_off(),
+            leave_days
+        );
+    }
+    Ok(())
+}
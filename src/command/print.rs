@@ -0,0 +1,354 @@
+use crate::command::offline_holiday_map;
+use crate::config::cli::ColorWhen;
+use crate::config::model::AppConfig;
+use crate::data::calendar::{CalendarDay, MonthCalendar};
+use crate::holiday::utils::{get_holiday_state, DayState};
+use crate::theme::theme_loader::load_theme_from_file;
+use crate::theme::theme_model::Theme;
+use crate::ui::day_cell::{get_day_item_style, get_holiday_name_style};
+use crate::ui::lunar::{number_to_lunar_day, number_to_lunar_month};
+use crate::ui::translate::{get_month_til_i18n, weekday_name_i18n};
+use crate::ui::utils::get_style_from_config;
+use chrono::{Datelike, Local, Months, NaiveDate};
+use color_eyre::eyre::{bail, eyre};
+use color_eyre::Result;
+use crossterm::style::{Attribute, ContentStyle};
+use ratatui::backend::IntoCrossterm;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use std::collections::HashMap;
+use std::io::{self, IsTerminal};
+use unicode_width::UnicodeWidthChar;
+
+/// 并排的两个月份之间的空格
+const MONTH_GAP: usize = 2;
+
+pub fn run_print(
+    month: Option<NaiveDate>,
+    months: u32,
+    year: Option<i32>,
+    columns: usize,
+    color: ColorWhen,
+    app_config: &AppConfig,
+) -> Result<()> {
+    if months == 0 || columns == 0 {
+        bail!("--months and --columns must be at least 1");
+    }
+    let (first, months) = match year {
+        Some(year) => (
+            NaiveDate::from_ymd_opt(year, 1, 1).ok_or_else(|| eyre!("invalid year {}", year))?,
+            12,
+        ),
+        None => (
+            month.unwrap_or_else(|| Local::now().date_naive().with_day(1).unwrap()),
+            months,
+        ),
+    };
+    let month_starts = (0..months)
+        .map(|i| first.checked_add_months(Months::new(i)))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| eyre!("month out of range"))?;
+    let theme = load_theme_from_file(&app_config.theme)
+        .map_err(|err| eyre!("failed to load theme {}: {}", app_config.theme, err))?;
+
+    // 不显示相邻月份的日期，只需要这几个月所在年份的节假日
+    let holiday_map = if app_config.show_holiday {
+        let last_year = month_starts.last().map_or(first.year(), |date| date.year());
+        offline_holiday_map(app_config, first.year()..=last_year)
+    } else {
+        HashMap::new()
+    };
+    let today = Local::now().date_naive();
+    let printer = MonthPrinter {
+        theme: &theme,
+        app_config,
+    };
+    let blocks: Vec<Vec<Line>> = month_starts
+        .iter()
+        .map(|start| {
+            let calendar = MonthCalendar::new(
+                start.year() as u32,
+                start.month(),
+                today,
+                &holiday_map,
+                &app_config.language,
+                &app_config.country,
+            );
+            printer.render_month(&calendar)
+        })
+        .collect();
+
+    let color = use_color(color);
+    let mut output = String::new();
+    for (i, row) in blocks.chunks(columns).enumerate() {
+        if i > 0 {
+            output.push('\n');
+        }
+        for line in join_months(row, printer.month_width()) {
+            output.push_str(&line_to_string(&line, color));
+            output.push('\n');
+        }
+    }
+    print!("{}", output);
+    Ok(())
+}
+
+/// 设置了 NO_COLOR 或者输出不是终端时不使用颜色
+fn use_color(color: ColorWhen) -> bool {
+    match color {
+        ColorWhen::Always => true,
+        ColorWhen::Never => false,
+        ColorWhen::Auto => {
+            std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                && io::stdout().is_terminal()
+        }
+    }
+}
+
+struct MonthPrinter<'a> {
+    theme: &'a Theme,
+    app_config: &'a AppConfig,
+}
+
+impl MonthPrinter<'_> {
+    /// 每一天占的宽度 (包含右侧的空格)，显示农历时要放下三个汉字
+    fn cell_width(&self) -> usize {
+        if self.app_config.show_lunar {
+            7
+        } else {
+            4
+        }
+    }
+
+    fn month_width(&self) -> usize {
+        self.cell_width() * 7
+    }
+
+    /// 标题、星期和每一周的日期；显示农历时每一周下面多一行农历或节日名称
+    fn render_month(&self, calendar: &MonthCalendar) -> Vec<Line<'static>> {
+        let language = &self.app_config.language;
+        let title = get_month_til_i18n(calendar.year as i32, calendar.month, language);
+        let title_style = get_style_from_config(Some(Style::default()), self.theme.month_til);
+        let mut lines = vec![centered(&title, self.month_width(), title_style)];
+
+        let head_style =
+            get_style_from_config(Some(self.theme.get_default_style()), self.theme.month_head);
+        lines.push(Line::from(
+            (0..7)
+                .flat_map(|i| {
+                    cell(
+                        &weekday_name_i18n(i, language),
+                        self.cell_width(),
+                        head_style,
+                    )
+                })
+                .collect::<Vec<_>>(),
+        ));
+
+        // MonthCalendar 前后会补上相邻月份的一周，整周都不属于这个月时不打印
+        for week in calendar
+            .day_data
+            .iter()
+            .filter(|week| week.iter().any(|day| day.is_current_month))
+        {
+            lines.push(Line::from(
+                week.iter()
+                    .flat_map(|day| self.day_spans(day))
+                    .collect::<Vec<_>>(),
+            ));
+            if self.app_config.show_lunar {
+                lines.push(Line::from(
+                    week.iter()
+                        .flat_map(|day| self.label_spans(day))
+                        .collect::<Vec<_>>(),
+                ));
+            }
+        }
+        lines
+    }
+
+    fn day_state(&self, day: &CalendarDay) -> (DayState, bool) {
+        get_holiday_state(
+            &day.holidays,
+            day.day_of_week as u16,
+            self.app_config.region.as_deref(),
+        )
+    }
+
+    /// 日期后面跟一个标记：* 放假，+ 调休上班，~ 半天假
+    fn day_spans(&self, day: &CalendarDay) -> Vec<Span<'static>> {
+        if !day.is_current_month {
+            return cell("", self.cell_width(), Style::default());
+        }
+        let (day_state, is_holiday) = self.day_state(day);
+        let marker = match day_state {
+            _ if !is_holiday || !self.app_config.show_holiday => ' ',
+            DayState::Rest => '*',
+            DayState::Work => '+',
+            DayState::HalfDay => '~',
+        };
+        let style = get_day_item_style(self.theme, day, day_state);
+        cell(
+            format!("{:>2}{}", day.day, marker).trim_end(),
+            self.cell_width(),
+            style,
+        )
+    }
+
+    /// 有节日时显示节日名称，否则显示农历日期 (初一显示月份)
+    fn label_spans(&self, day: &CalendarDay) -> Vec<Span<'static>> {
+        if !day.is_current_month {
+            return cell("", self.cell_width(), Style::default());
+        }
+        let style = get_day_item_style(self.theme, day, self.day_state(day).0);
+        let holiday = day.holidays.iter().flatten().find(|holiday| {
+            self.app_config.show_holiday
+                && holiday.is_visible_in_region(self.app_config.region.as_deref())
+                && self.app_config.holiday_types.is_visible(holiday)
+        });
+        match holiday {
+            Some(holiday) => cell(
+                &holiday.name,
+                self.cell_width(),
+                get_holiday_name_style(self.app_config, holiday, style),
+            ),
+            None if day.lunar_day == 1 => cell(
+                &number_to_lunar_month(day.lunar_month),
+                self.cell_width(),
+                style,
+            ),
+            None => cell(
+                &number_to_lunar_day(day.lunar_day),
+                self.cell_width(),
+                style,
+            ),
+        }
+    }
+}
+
+/// 截断到 width - 1 的宽度并用空格补齐到 width，留出一个空格与右边隔开
+fn cell(text: &str, width: usize, style: Style) -> Vec<Span<'static>> {
+    let text = truncate(text, width.saturating_sub(1));
+    let padding = width - display_width(&text);
+    vec![Span::styled(text, style), Span::raw(" ".repeat(padding))]
+}
+
+fn centered(text: &str, width: usize, style: Style) -> Line<'static> {
+    let text = truncate(text, width);
+    let padding = width - display_width(&text);
+    Line::from(vec![
+        Span::raw(" ".repeat(padding / 2)),
+        Span::styled(text, style),
+        Span::raw(" ".repeat(padding - padding / 2)),
+    ])
+}
+
+fn display_width(text: &str) -> usize {
+    text.chars().map(|c| c.width().unwrap_or(0)).sum()
+}
+
+fn truncate(text: &str, max_width: usize) -> String {
+    let mut width = 0;
+    text.chars()
+        .take_while(|c| {
+            width += c.width().unwrap_or(0);
+            width <= max_width
+        })
+        .collect()
+}
+
+/// 把一行中的几个月并排，行数少的月份用空格补齐
+fn join_months(months: &[Vec<Line<'static>>], month_width: usize) -> Vec<Line<'static>> {
+    let height = months.iter().map(Vec::len).max().unwrap_or(0);
+    (0..height)
+        .map(|row| {
+            let mut spans = vec![];
+            for (i, month) in months.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::raw(" ".repeat(MONTH_GAP)));
+                }
+                match month.get(row) {
+                    Some(line) => spans.extend(line.spans.iter().cloned()),
+                    None => spans.push(Span::raw(" ".repeat(month_width))),
+                }
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// 转换成文本，color 为 true 时用 ANSI 转义序列输出样式
+fn line_to_string(line: &Line, color: bool) -> String {
+    let text: String = line
+        .spans
+        .iter()
+        .map(|span| {
+            if color && span.style != Style::default() {
+                to_content_style(span.style)
+                    .apply(span.content.as_ref())
+                    .to_string()
+            } else {
+                span.content.to_string()
+            }
+        })
+        .collect();
+    text.trim_end().to_string()
+}
+
+fn to_content_style(style: Style) -> ContentStyle {
+    let mut content_style = ContentStyle::new();
+    content_style.foreground_color = style.fg.map(IntoCrossterm::into_crossterm);
+    content_style.background_color = style.bg.map(IntoCrossterm::into_crossterm);
+    for (modifier, attribute) in [
+        (Modifier::BOLD, Attribute::Bold),
+        (Modifier::DIM, Attribute::Dim),
+        (Modifier::ITALIC, Attribute::Italic),
+        (Modifier::UNDERLINED, Attribute::Underlined),
+        (Modifier::REVERSED, Attribute::Reverse),
+        (Modifier::CROSSED_OUT, Attribute::CrossedOut),
+    ] {
+        if style.add_modifier.contains(modifier) {
+            content_style.attributes.set(attribute);
+        }
+    }
+    content_style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_cell_with_wide_chars() {
+        // 宽度 7 的格子最多放三个汉字
+        let spans = cell("国庆节假期", 7, Style::default());
+        assert_eq!(spans[0].content, "国庆节");
+        assert_eq!(spans[1].content, " ");
+        let spans = cell("初一", 7, Style::default());
+        assert_eq!(spans[1].content, "   ");
+        assert_eq!(truncate("中秋节", 5), "中秋");
+    }
+
+    #[test]
+    fn test_line_to_string() {
+        let line = Line::from(vec![
+            Span::styled(" 1*", Style::default().fg(Color::Rgb(255, 0, 0)).bold()),
+            Span::raw("   "),
+        ]);
+        assert_eq!(line_to_string(&line, false), " 1*");
+        let colored = line_to_string(&line, true);
+        assert!(colored.contains("\u{1b}[38;2;255;0;0m"));
+        assert!(colored.contains(" 1*"));
+        assert!(!colored.ends_with(' '));
+    }
+
+    #[test]
+    fn test_join_months() {
+        let month = |rows: usize| vec![Line::from("x".repeat(4)); rows];
+        let lines = join_months(&[month(2), month(1)], 4);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(line_to_string(&lines[0], false), "xxxx  xxxx");
+        assert_eq!(line_to_string(&lines[1], false), "xxxx");
+    }
+}
@@ -0,0 +1,28 @@
+use crate::command::{offline_workday_calendar, warn_years_without_data};
+use crate::config::cli::WorkdaysAction;
+use crate::config::model::AppConfig;
+use chrono::Local;
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+pub fn run_workdays_action(action: WorkdaysAction, app_config: &AppConfig) -> Result<()> {
+    let calendar = offline_workday_calendar(app_config);
+    let output = match action {
+        WorkdaysAction::Between { from, to } => Ok(calendar.workdays_between(from, to).to_string()),
+        WorkdaysAction::Add { date, days } => calendar
+            .add_workdays(date, days)
+            .map(|result| result.format(&app_config.output).to_string())
+            .ok_or_else(|| eyre!("no working day found")),
+        WorkdaysAction::Next { date } => {
+            let date = date.unwrap_or_else(|| Local::now().date_naive());
+            calendar
+                .next_workday(date)
+                .map(|result| result.format(&app_config.output).to_string())
+                .ok_or_else(|| eyre!("no working day found"))
+        }
+    };
+    // 结果用到了没有数据的年份时，无论成功与否都提示
+    warn_years_without_data(&calendar, app_config);
+    println!("{}", output?);
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use clap::Parser;
+use chrono::NaiveDate;
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 
 #[derive(Debug, Parser)]
 #[command(version,about,long_about = None)]
@@ -7,13 +9,29 @@ pub struct Args {
     #[arg(short, long)]
     pub country: Option<String>,
 
+    /// ISO 3166-2 subdivision (e.g. de-BY), regional holidays of this region are days off
+    #[arg(long)]
+    pub region: Option<String>,
+
     /// language
     #[arg(short, long)]
     pub language: Option<String>,
 
-    /// holiday data source (github or gitee)
-    #[arg(long, default_value = "github")]
-    pub source: Option<String>,
+    /// holiday data sources in priority order (github, gitee, a URL template or a file:// directory)
+    #[arg(long, value_delimiter = ',', default_value = "github")]
+    pub source: Option<Vec<String>>,
+
+    /// timeout in seconds for downloading holiday data
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub http_timeout: Option<u64>,
+
+    /// how many times a failed download is retried
+    #[arg(long)]
+    pub http_retries: Option<u32>,
+
+    /// HTTP(S) proxy for downloading holiday data (e.g. http://127.0.0.1:7890)
+    #[arg(long)]
+    pub proxy: Option<String>,
 
     /// hide background colors
     #[arg(long, num_args(0..=1), default_missing_value = "true")]
@@ -31,10 +49,196 @@ pub struct Args {
     #[arg( long, num_args(0..=1),default_missing_value = "true")]
     pub show_holiday: Option<bool>,
 
+    /// show the Japanese rokuyō (大安, 仏滅 …) of each day
+    #[arg( long, num_args(0..=1),default_missing_value = "true")]
+    pub show_rokuyo: Option<bool>,
+
+    /// show the moon phase icon of each day
+    #[arg( long, num_args(0..=1),default_missing_value = "true")]
+    pub show_moon_phase: Option<bool>,
+
+    /// moon phase icon style (nerd or ascii)
+    #[arg(long)]
+    pub moon_phase_glyph: Option<String>,
+
+    /// show a one-line 宜/忌 summary of the selected day in the bottom line
+    #[arg( long, num_args(0..=1),default_missing_value = "true")]
+    pub show_almanac_summary: Option<bool>,
+
+    /// show the days until the next holiday in the bottom line
+    #[arg( long, num_args(0..=1),default_missing_value = "true")]
+    pub show_countdown: Option<bool>,
+
     #[arg(short, long, default_value = "%Y-%m-%d")]
     pub output: Option<String>,
 
     /// theme name (ningmen, ocean, forest, sunset, lavender, mint, ruby)
     #[arg(long)]
     pub theme: Option<String>,
+
+    /// alternate calendars shown below the lunar date (islamic, hebrew, persian, buddhist)
+    #[arg(long, value_delimiter = ',')]
+    pub alternate_calendar: Option<Vec<String>>,
+
+    /// other countries whose holidays are shown as badges (e.g. de,us)
+    #[arg(long, value_delimiter = ',')]
+    pub overlay_country: Option<Vec<String>>,
+
+    /// holiday types whose names are not shown (e.g. observance,season)
+    #[arg(long, value_delimiter = ',')]
+    pub hide_holiday_type: Option<Vec<String>>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl Args {
+    /// 解析命令行参数。--source 的默认值只用于帮助信息，
+    /// 命令行没有指定时置为 None，这样配置文件中的 sources 才会生效
+    pub fn parse_args() -> Self {
+        let matches = Args::command().get_matches();
+        let mut args = Args::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+        if matches.value_source("source") == Some(ValueSource::DefaultValue) {
+            args.source = None;
+        }
+        args
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// manage holiday data
+    Holidays {
+        #[command(subcommand)]
+        command: HolidaysCommand,
+    },
+    /// working-day arithmetic that knows about holidays and make-up workdays
+    Workdays {
+        #[command(subcommand)]
+        action: WorkdaysAction,
+    },
+    /// suggest leave days that give the longest continuous breaks
+    PlanLeave {
+        /// the year to plan, this year by default
+        #[arg(long)]
+        year: Option<i32>,
+        /// how many leave days can be taken for one break
+        #[arg(long)]
+        days: usize,
+        /// how many suggestions to show
+        #[arg(long, default_value_t = 5)]
+        limit: usize,
+    },
+    /// print months to stdout like cal(1), without entering the TUI
+    Print {
+        /// the first month to print (e.g. 2026-10), this month by default
+        #[arg(value_parser = parse_year_month)]
+        month: Option<NaiveDate>,
+        /// how many months to print
+        #[arg(short = 'n', long, default_value_t = 1)]
+        months: u32,
+        /// print the twelve months of a year
+        #[arg(long, conflicts_with_all = ["month", "months"])]
+        year: Option<i32>,
+        /// how many months to put side by side
+        #[arg(long, default_value_t = 3)]
+        columns: usize,
+        /// when to use colors, auto honors NO_COLOR and only colors a terminal
+        #[arg(long, value_enum, default_value_t = ColorWhen::Auto)]
+        color: ColorWhen,
+    },
+    /// write one record per day between two dates as JSON or CSV
+    Dump {
+        /// the first day, included
+        #[arg(long)]
+        from: NaiveDate,
+        /// the last day, included
+        #[arg(long)]
+        to: NaiveDate,
+        #[arg(long, value_enum, default_value_t = DumpFormat::Json)]
+        format: DumpFormat,
+    },
+    /// exit with 0 on a working day, 1 on a day off and 2 without holiday data
+    IsWorkday {
+        #[command(flatten)]
+        query: DayQuery,
+    },
+    /// exit with 0 on a day off, 1 on a working day and 2 without holiday data
+    IsHoliday {
+        #[command(flatten)]
+        query: DayQuery,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+pub struct DayQuery {
+    /// the day to check, today by default
+    #[arg(long)]
+    pub date: Option<NaiveDate>,
+    /// the country of holiday, overrides the global option
+    #[arg(short, long)]
+    pub country: Option<String>,
+    /// language, overrides the global option
+    #[arg(short, long)]
+    pub language: Option<String>,
+    /// download the holiday data of that year first instead of only using cached or bundled data
+    #[arg(long)]
+    pub fetch: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum DumpFormat {
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ColorWhen {
+    Auto,
+    Always,
+    Never,
+}
+
+/// 解析 yyyy-mm 格式的月份，返回这个月的第一天
+fn parse_year_month(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(&format!("{}-01", value), "%Y-%m-%d")
+        .map_err(|_| format!("invalid month '{}', expected yyyy-mm", value))
+}
+
+#[derive(Debug, Subcommand)]
+pub enum WorkdaysAction {
+    /// count the working days between two dates, both ends included
+    Between { from: NaiveDate, to: NaiveDate },
+    /// the date N working days after the given date (before it when N is negative)
+    Add {
+        date: NaiveDate,
+        #[arg(allow_negative_numbers = true)]
+        days: i64,
+    },
+    /// the first working day after the given date, today by default
+    Next { date: Option<NaiveDate> },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum HolidaysCommand {
+    /// inspect, refresh and prune the holiday cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CacheAction {
+    /// list cached files with version, modified time and source
+    List,
+    /// download the given keys (e.g. 2026_zh_cn) again, all cached keys by default
+    Refresh { keys: Vec<String> },
+    /// remove cached years before the given year, last year by default
+    Prune {
+        #[arg(long)]
+        before: Option<i32>,
+    },
+    /// check that every cached file can be parsed
+    Verify,
 }
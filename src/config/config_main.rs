@@ -1,6 +1,8 @@
 use super::{config_file_loader::load_file_config, locale, model::AppConfig};
 use crate::config::cli::Args;
-use crate::config::model::Source;
+use crate::config::model::{parse_sources, HolidayTypeDisplay, OverlayCountryConfig, Source};
+use crate::data::alternate_calendar::parse_alternate_calendars;
+use crate::data::moon_phase::MoonPhaseGlyph;
 
 pub fn get_app_config(args: Args) -> AppConfig {
     let (language, country_option) = locale::get_system_language_country();
@@ -8,15 +10,29 @@ pub fn get_app_config(args: Args) -> AppConfig {
     let mut app_config = AppConfig {
         language,
         country: String::from("cn"),
+        region: None,
         column: None,
         row: None,
         show_lunar: false,
         show_holiday: false,
+        show_rokuyo: false,
+        show_moon_phase: false,
+        moon_phase_glyph: MoonPhaseGlyph::Nerd,
+        show_almanac_summary: false,
+        show_countdown: false,
         output: "%Y-%m-%d".to_string(),
-        source: Source::Github,
+        sources: vec![Source::Github],
+        http_timeout: 10,
+        http_retries: 3,
+        proxy: None,
         hide_bg: true,
         theme: "ningmen".to_string(),
+        alternate_calendars: vec![],
+        overlay_countries: vec![],
+        holiday_types: HolidayTypeDisplay::default(),
     };
+    // 叠加国家的默认语言取决于最终的 language，所以最后再解析
+    let mut overlay_configs: Vec<OverlayCountryConfig> = vec![];
     if let Some(country) = country_option {
         app_config.country = country
     }
@@ -29,6 +45,9 @@ pub fn get_app_config(args: Args) -> AppConfig {
         if let Some(country) = file_config.country {
             app_config.country = country;
         }
+        if let Some(region) = file_config.region {
+            app_config.region = Some(region);
+        }
         if let Some(file_column) = file_config.column {
             app_config.column = Some(file_column);
         }
@@ -41,26 +60,74 @@ pub fn get_app_config(args: Args) -> AppConfig {
         if let Some(show_holiday) = file_config.show_holiday {
             app_config.show_holiday = show_holiday;
         }
+        if let Some(show_rokuyo) = file_config.show_rokuyo {
+            app_config.show_rokuyo = show_rokuyo;
+        }
+        if let Some(show_moon_phase) = file_config.show_moon_phase {
+            app_config.show_moon_phase = show_moon_phase;
+        }
+        if let Some(glyph_str) = file_config.moon_phase_glyph {
+            if let Ok(glyph) = glyph_str.parse::<MoonPhaseGlyph>() {
+                app_config.moon_phase_glyph = glyph;
+            }
+        }
+        if let Some(show_almanac_summary) = file_config.show_almanac_summary {
+            app_config.show_almanac_summary = show_almanac_summary;
+        }
+        if let Some(show_countdown) = file_config.show_countdown {
+            app_config.show_countdown = show_countdown;
+        }
         if let Some(file_output) = file_config.output {
             app_config.output = file_output;
         }
         if let Some(source_str) = file_config.source {
             if let Ok(source) = source_str.parse::<Source>() {
-                app_config.source = source;
+                app_config.sources = vec![source];
+            }
+        }
+        if let Some(sources) = file_config.sources {
+            let sources = parse_sources(&sources);
+            if !sources.is_empty() {
+                app_config.sources = sources;
             }
         }
+        // 超时为 0 时 reqwest 的每个请求都会立即超时，不接受
+        match file_config.http_timeout {
+            Some(0) => eprintln!("http_timeout must be greater than 0, ignored"),
+            Some(http_timeout) => app_config.http_timeout = http_timeout,
+            None => {}
+        }
+        if let Some(http_retries) = file_config.http_retries {
+            app_config.http_retries = http_retries;
+        }
+        if let Some(proxy) = file_config.proxy {
+            app_config.proxy = Some(proxy);
+        }
         if let Some(hide_bg) = file_config.hide_bg {
             app_config.hide_bg = hide_bg;
         }
         if let Some(theme) = file_config.theme {
             app_config.theme = theme;
         }
+        if let Some(alternate_calendars) = file_config.alternate_calendars {
+            app_config.alternate_calendars = parse_alternate_calendars(&alternate_calendars);
+        }
+        if let Some(overlay_countries) = file_config.overlay_countries {
+            overlay_configs = overlay_countries;
+        }
+        if let Some(holiday_types) = file_config.holiday_types {
+            app_config.holiday_types = HolidayTypeDisplay::new(holiday_types);
+        }
     }
 
     if let Some(arg_country) = args.country {
         app_config.country = arg_country.to_string()
     }
 
+    if let Some(arg_region) = args.region {
+        app_config.region = Some(arg_region);
+    }
+
     if let Some(arg_language) = args.language {
         app_config.language = arg_language.to_string()
     }
@@ -81,16 +148,51 @@ pub fn get_app_config(args: Args) -> AppConfig {
         app_config.show_holiday = arg_show_holiday;
     }
 
+    if let Some(arg_show_rokuyo) = args.show_rokuyo {
+        app_config.show_rokuyo = arg_show_rokuyo;
+    }
+
+    if let Some(arg_show_moon_phase) = args.show_moon_phase {
+        app_config.show_moon_phase = arg_show_moon_phase;
+    }
+
+    if let Some(arg_glyph) = args.moon_phase_glyph {
+        if let Ok(glyph) = arg_glyph.parse::<MoonPhaseGlyph>() {
+            app_config.moon_phase_glyph = glyph;
+        }
+    }
+
+    if let Some(arg_show_almanac_summary) = args.show_almanac_summary {
+        app_config.show_almanac_summary = arg_show_almanac_summary;
+    }
+
+    if let Some(arg_show_countdown) = args.show_countdown {
+        app_config.show_countdown = arg_show_countdown;
+    }
+
     if let Some(arg_output) = args.output {
         app_config.output = arg_output;
     }
 
-    if let Some(arg_source) = args.source {
-        if let Ok(source) = arg_source.parse::<Source>() {
-            app_config.source = source;
+    if let Some(arg_sources) = args.source {
+        let sources = parse_sources(&arg_sources);
+        if !sources.is_empty() {
+            app_config.sources = sources;
         }
     }
 
+    if let Some(arg_http_timeout) = args.http_timeout {
+        app_config.http_timeout = arg_http_timeout;
+    }
+
+    if let Some(arg_http_retries) = args.http_retries {
+        app_config.http_retries = arg_http_retries;
+    }
+
+    if let Some(arg_proxy) = args.proxy {
+        app_config.proxy = Some(arg_proxy);
+    }
+
     if let Some(arg_hide_bg) = args.hide_bg {
         app_config.hide_bg = arg_hide_bg;
     }
@@ -99,5 +201,26 @@ pub fn get_app_config(args: Args) -> AppConfig {
         app_config.theme = arg_theme;
     }
 
+    if let Some(arg_alternate_calendars) = args.alternate_calendar {
+        app_config.alternate_calendars = parse_alternate_calendars(&arg_alternate_calendars);
+    }
+
+    if let Some(arg_overlay_countries) = args.overlay_country {
+        overlay_configs = arg_overlay_countries
+            .iter()
+            .map(|country| OverlayCountryConfig::from_country(country))
+            .collect();
+    }
+
+    if let Some(arg_hide_holiday_types) = args.hide_holiday_type {
+        app_config.holiday_types.hide(&arg_hide_holiday_types);
+    }
+
+    app_config.overlay_countries = overlay_configs
+        .into_iter()
+        .enumerate()
+        .map(|(index, overlay)| overlay.resolve(index, &app_config.language))
+        .collect();
+
     app_config
 }
@@ -1,20 +1,38 @@
+use crate::data::alternate_calendar::AlternateCalendarKind;
+use crate::data::moon_phase::MoonPhaseGlyph;
+use crate::holiday::modal::{Holiday, PrimaryType};
+use crate::theme::theme_model::ItemStyle;
+use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Source {
     Github,
     Gitee,
+    /// 自定义数据源：带 {year}/{language}/{country} 占位符的 URL 模板，
+    /// 或者与 resources/holidays 结构相同的目录 (http(s):// 或 file://)
+    Custom(String),
 }
 
 impl Source {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Source::Github => "github",
             Source::Gitee => "gitee",
+            Source::Custom(template) => template,
         }
     }
 }
 
+/// 解析数据源列表，忽略无法识别的数据源
+pub fn parse_sources(sources: &[String]) -> Vec<Source> {
+    sources
+        .iter()
+        .filter_map(|source| source.trim().parse::<Source>().ok())
+        .collect()
+}
+
 impl std::str::FromStr for Source {
     type Err = String;
 
@@ -22,35 +40,270 @@ impl std::str::FromStr for Source {
         match s.to_lowercase().as_str() {
             "github" => Ok(Source::Github),
             "gitee" => Ok(Source::Gitee),
+            _ if ["http://", "https://", "file://"]
+                .iter()
+                .any(|scheme| s.starts_with(scheme)) =>
+            {
+                Ok(Source::Custom(s.to_string()))
+            }
             _ => Err(format!("Unknown source: {}", s)),
         }
     }
 }
 
+/// 叠加显示的其他国家节假日
+#[derive(Debug, Clone)]
+pub struct OverlayCountry {
+    pub country: String,
+    pub language: String,
+    /// 日历格子中显示的标记，默认为大写的国家代码
+    pub marker: String,
+    pub color: Color,
+}
+
+/// 叠加国家没有指定颜色时依次使用的颜色
+const OVERLAY_COLORS: [Color; 5] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+];
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OverlayCountryConfig {
+    pub country: String,
+    pub language: Option<String>,
+    pub marker: Option<String>,
+    pub color: Option<String>,
+}
+
+impl OverlayCountryConfig {
+    pub fn from_country(country: &str) -> Self {
+        OverlayCountryConfig {
+            country: country.trim().to_string(),
+            language: None,
+            marker: None,
+            color: None,
+        }
+    }
+
+    /// 补全默认值，language 默认使用应用的语言
+    pub fn resolve(self, index: usize, default_language: &str) -> OverlayCountry {
+        OverlayCountry {
+            marker: self.marker.unwrap_or_else(|| self.country.to_uppercase()),
+            language: self
+                .language
+                .unwrap_or_else(|| default_language.to_string()),
+            color: self
+                .color
+                .and_then(|color| color.parse::<Color>().ok())
+                .unwrap_or(OVERLAY_COLORS[index % OVERLAY_COLORS.len()]),
+            country: self.country,
+        }
+    }
+}
+
+/// 某一类节日 (primary_type) 的显示设置，只影响节日名称的显示，不影响是否放假
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct HolidayTypeConfig {
+    #[serde(default = "default_true")]
+    pub show: bool,
+    /// 用暗淡的颜色显示
+    #[serde(default)]
+    pub dim: bool,
+    #[serde(flatten)]
+    pub style: ItemStyle,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl HolidayTypeConfig {
+    pub fn hidden() -> Self {
+        HolidayTypeConfig {
+            show: false,
+            dim: false,
+            style: ItemStyle::default(),
+        }
+    }
+}
+
+/// 按 primary_type 的显示设置，key 为统一格式后的类型名称，如 observance、common_holiday
+#[derive(Debug, Clone, Default)]
+pub struct HolidayTypeDisplay {
+    types: HashMap<String, HolidayTypeConfig>,
+}
+
+/// "Common holiday"、"common-holiday" 和 "common_holiday" 视为同一个类型
+fn normalize_holiday_type(name: &str) -> String {
+    name.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+impl HolidayTypeDisplay {
+    pub fn new(types: HashMap<String, HolidayTypeConfig>) -> Self {
+        HolidayTypeDisplay {
+            types: types
+                .into_iter()
+                .map(|(name, config)| (normalize_holiday_type(&name), config))
+                .collect(),
+        }
+    }
+
+    /// 隐藏指定的类型，保留已经设置的样式
+    pub fn hide(&mut self, names: &[String]) {
+        for name in names {
+            self.types
+                .entry(normalize_holiday_type(name))
+                .and_modify(|config| config.show = false)
+                .or_insert_with(HolidayTypeConfig::hidden);
+        }
+    }
+
+    pub fn get(&self, primary_type: &PrimaryType) -> Option<&HolidayTypeConfig> {
+        self.types
+            .get(&normalize_holiday_type(&primary_type.name()))
+    }
+
+    pub fn is_visible(&self, holiday: &Holiday) -> bool {
+        self.get(&holiday.primary_type)
+            .is_none_or(|config| config.show)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub country: String,
+    pub region: Option<String>,
     pub language: String,
     pub column: Option<u32>,
     pub row: Option<u32>,
     pub show_lunar: bool,
     pub show_holiday: bool,
+    pub show_rokuyo: bool,
+    pub show_moon_phase: bool,
+    pub moon_phase_glyph: MoonPhaseGlyph,
+    pub show_almanac_summary: bool,
+    /// 在底部显示距离下一个放假节日的天数
+    pub show_countdown: bool,
     pub output: String,
-    pub source: Source,
+    /// 节假日数据源，按顺序尝试，失败时使用下一个
+    pub sources: Vec<Source>,
+    /// 下载节假日数据的超时时间（秒）
+    pub http_timeout: u64,
+    /// 下载失败时的重试次数
+    pub http_retries: u32,
+    /// HTTP(S) 代理地址
+    pub proxy: Option<String>,
     pub hide_bg: bool,
     pub theme: String,
+    pub alternate_calendars: Vec<AlternateCalendarKind>,
+    pub overlay_countries: Vec<OverlayCountry>,
+    /// 按节日类型隐藏或设置样式
+    pub holiday_types: HolidayTypeDisplay,
+}
+
+impl AppConfig {
+    /// 测试用的配置：zh/cn，只显示节假日，不读取配置文件和系统语言
+    #[cfg(test)]
+    pub fn for_test() -> Self {
+        AppConfig {
+            country: "cn".to_string(),
+            region: None,
+            language: "zh".to_string(),
+            column: None,
+            row: None,
+            show_lunar: false,
+            show_holiday: true,
+            show_rokuyo: false,
+            show_moon_phase: false,
+            moon_phase_glyph: MoonPhaseGlyph::Ascii,
+            show_almanac_summary: false,
+            show_countdown: false,
+            output: "%Y-%m-%d".to_string(),
+            sources: vec![],
+            http_timeout: 10,
+            http_retries: 0,
+            proxy: None,
+            hide_bg: true,
+            theme: "ningmen".to_string(),
+            alternate_calendars: vec![],
+            overlay_countries: vec![],
+            holiday_types: HolidayTypeDisplay::default(),
+        }
+    }
+
+    /// 需要加载节假日数据的 (language, country)，第一个为主国家
+    pub fn holiday_language_countries(&self) -> Vec<(String, String)> {
+        let mut keys = vec![(self.language.clone(), self.country.clone())];
+        for overlay in &self.overlay_countries {
+            let key = (overlay.language.clone(), overlay.country.clone());
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        keys
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ConfigFile {
     pub language: Option<String>,
     pub country: Option<String>,
+    pub region: Option<String>,
     pub show_lunar: Option<bool>,
     pub show_holiday: Option<bool>,
+    pub show_rokuyo: Option<bool>,
+    pub show_moon_phase: Option<bool>,
+    pub moon_phase_glyph: Option<String>,
+    pub show_almanac_summary: Option<bool>,
+    pub show_countdown: Option<bool>,
     pub hide_bg: Option<bool>,
     pub column: Option<u32>,
     pub row: Option<u32>,
     pub output: Option<String>,
     pub source: Option<String>,
+    pub sources: Option<Vec<String>>,
+    pub http_timeout: Option<u64>,
+    pub http_retries: Option<u32>,
+    pub proxy: Option<String>,
     pub theme: Option<String>,
+    pub alternate_calendars: Option<Vec<String>>,
+    pub overlay_countries: Option<Vec<OverlayCountryConfig>>,
+    #[serde(skip_serializing)]
+    pub holiday_types: Option<HashMap<String, HolidayTypeConfig>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_holiday_type_display() {
+        let config: ConfigFile = toml::from_str(
+            r##"
+            [holiday_types.season]
+            show = false
+
+            [holiday_types."Observance"]
+            dim = true
+            fg = "#808080"
+            "##,
+        )
+        .unwrap();
+        let mut display = HolidayTypeDisplay::new(config.holiday_types.unwrap());
+        assert!(display.get(&PrimaryType::Season).is_some_and(|c| !c.show));
+        let observance = display.get(&PrimaryType::Observance).unwrap();
+        assert!(observance.show && observance.dim);
+        assert_eq!(observance.style.fg, Some(Color::Rgb(128, 128, 128)));
+        assert!(display.get(&PrimaryType::NationalHoliday).is_none());
+
+        display.hide(&["common-holiday".to_string(), "observance".to_string()]);
+        assert!(display
+            .get(&PrimaryType::CommonHoliday)
+            .is_some_and(|c| !c.show));
+        let observance = display.get(&PrimaryType::Observance).unwrap();
+        assert!(!observance.show && observance.dim);
+    }
 }
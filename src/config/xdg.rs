@@ -21,6 +21,7 @@ impl Xdg {
     /// - Linux:   $XDG_DATA_HOME/riqi  或  ~/.local/share/riqi
     /// - macOS:  ~/Library/Application Support/riqi
     /// - Windows: C:\Users\Alice\AppData\Roaming\riqi
+    #[allow(dead_code)]
     pub fn data_dir() -> Option<PathBuf> {
         dirs::data_dir().map(|path| path.join("riqi"))
     }
@@ -0,0 +1,106 @@
+use chrono::{Datelike, NaiveDate};
+use tyme4rs::tyme::lunar::LunarDay;
+use tyme4rs::tyme::sixtycycle::HeavenStem;
+use tyme4rs::tyme::solar::SolarDay;
+use tyme4rs::tyme::Culture;
+
+/// 某一天的黄历信息
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    /// 干支日，例如 "甲子"
+    pub sixty_cycle: String,
+    /// 宜
+    pub recommends: Vec<String>,
+    /// 忌
+    pub avoids: Vec<String>,
+    /// 冲煞，例如 "冲马(戊午)煞南"
+    pub clash: String,
+    /// 值神，例如 "青龙(黄道)"
+    pub twelve_star: String,
+    /// 建除十二值
+    pub duty: String,
+    /// 吉神方位：(神名, 方位)
+    pub directions: Vec<(&'static str, String)>,
+    /// 吉神宜趋
+    pub lucky_gods: Vec<String>,
+    /// 凶神宜忌
+    pub unlucky_gods: Vec<String>,
+}
+
+pub fn get_almanac(date: NaiveDate) -> Almanac {
+    let lunar_day: LunarDay = SolarDay::from_ymd(
+        date.year() as isize,
+        date.month() as usize,
+        date.day() as usize,
+    )
+    .get_lunar_day();
+    let sixty_cycle = lunar_day.get_sixty_cycle();
+    let heaven_stem = sixty_cycle.get_heaven_stem();
+    let earth_branch = sixty_cycle.get_earth_branch();
+
+    // 冲：地支相冲，天干取当日天干所克者，例如甲子日冲戊午
+    let opposite = earth_branch.get_opposite();
+    let clash = format!(
+        "冲{}({}{})煞{}",
+        opposite.get_zodiac().get_name(),
+        HeavenStem::from_index(heaven_stem.get_index() as isize + 4).get_name(),
+        opposite.get_name(),
+        earth_branch.get_ominous().get_name()
+    );
+
+    let twelve_star = lunar_day.get_twelve_star();
+    let twelve_star = format!(
+        "{}({})",
+        twelve_star.get_name(),
+        twelve_star.get_ecliptic().get_name()
+    );
+
+    let directions = vec![
+        ("喜神", heaven_stem.get_joy_direction().get_name()),
+        ("福神", heaven_stem.get_mascot_direction().get_name()),
+        ("财神", heaven_stem.get_wealth_direction().get_name()),
+        ("阳贵", heaven_stem.get_yang_direction().get_name()),
+        ("阴贵", heaven_stem.get_yin_direction().get_name()),
+    ];
+
+    let (lucky_gods, unlucky_gods): (Vec<_>, Vec<_>) = lunar_day
+        .get_gods()
+        .into_iter()
+        .partition(|god| god.get_luck().get_name() == "吉");
+
+    Almanac {
+        sixty_cycle: sixty_cycle.get_name(),
+        recommends: lunar_day
+            .get_recommends()
+            .iter()
+            .map(|taboo| taboo.get_name())
+            .collect(),
+        avoids: lunar_day
+            .get_avoids()
+            .iter()
+            .map(|taboo| taboo.get_name())
+            .collect(),
+        clash,
+        twelve_star,
+        duty: lunar_day.get_duty().get_name(),
+        directions,
+        lucky_gods: lucky_gods.iter().map(|god| god.get_name()).collect(),
+        unlucky_gods: unlucky_gods.iter().map(|god| god.get_name()).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_almanac() {
+        // 2024-01-01 为甲子日
+        let almanac = get_almanac(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert_eq!(almanac.sixty_cycle, "甲子");
+        assert_eq!(almanac.clash, "冲马(戊午)煞南");
+        assert_eq!(almanac.directions[0], ("喜神", "东北".to_string()));
+        assert!(!almanac.recommends.is_empty());
+        assert!(!almanac.avoids.is_empty());
+    }
+}
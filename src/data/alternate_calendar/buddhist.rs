@@ -0,0 +1,35 @@
+use super::AlternateCalendar;
+use chrono::{Datelike, NaiveDate};
+
+/// 泰国佛历：月日与公历相同，年份为公历年 + 543
+pub struct BuddhistCalendar;
+
+const BUDDHIST_ERA_OFFSET: i32 = 543;
+
+pub fn buddhist_era_year(date: NaiveDate) -> i32 {
+    date.year() + BUDDHIST_ERA_OFFSET
+}
+
+impl AlternateCalendar for BuddhistCalendar {
+    fn date_label(&self, date: NaiveDate) -> String {
+        format!(
+            "{}/{}/{}",
+            date.day(),
+            date.month(),
+            buddhist_era_year(date)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_label() {
+        let calendar = BuddhistCalendar;
+        let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        assert_eq!(buddhist_era_year(date), 2569);
+        assert_eq!(calendar.date_label(date), "19/10/2569");
+    }
+}
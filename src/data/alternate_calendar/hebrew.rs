@@ -0,0 +1,173 @@
+use super::AlternateCalendar;
+use chrono::{Datelike, NaiveDate};
+
+/// 希伯来历（按 Dershowitz & Reingold 的算术规则计算）
+pub struct HebrewCalendar;
+
+/// 希伯来历纪元（公元前 3761-10-07 儒略历）对应的公元日数
+const HEBREW_EPOCH: i64 = -1_373_427;
+
+/// 月份编号从尼散月 (1) 开始，提斯利月 (7) 为新年
+const NISAN: i64 = 1;
+const TISHRI: i64 = 7;
+
+fn is_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+fn last_month_of_year(year: i64) -> i64 {
+    if is_leap_year(year) {
+        13
+    } else {
+        12
+    }
+}
+
+/// 从纪元到该年提斯利月初一的天数（未做年长修正）
+fn calendar_elapsed_days(year: i64) -> i64 {
+    let months_elapsed = (235 * year - 234).div_euclid(19);
+    let parts_elapsed = 12084 + 13753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+fn year_length_correction(year: i64) -> i64 {
+    let ny0 = calendar_elapsed_days(year - 1);
+    let ny1 = calendar_elapsed_days(year);
+    let ny2 = calendar_elapsed_days(year + 1);
+    if ny2 - ny1 == 356 {
+        2
+    } else if ny1 - ny0 == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+fn new_year(year: i64) -> i64 {
+    HEBREW_EPOCH + calendar_elapsed_days(year) + year_length_correction(year)
+}
+
+fn days_in_year(year: i64) -> i64 {
+    new_year(year + 1) - new_year(year)
+}
+
+fn last_day_of_month(month: i64, year: i64) -> i64 {
+    let days = days_in_year(year);
+    let short = match month {
+        2 | 4 | 6 | 10 | 13 => true,
+        12 => !is_leap_year(year),
+        // 赫舍汪月只有在"完整年"才是 30 天
+        8 => days % 10 != 5,
+        // 基斯流月在"缺年"只有 29 天
+        9 => days % 10 == 3,
+        _ => false,
+    };
+    if short {
+        29
+    } else {
+        30
+    }
+}
+
+fn fixed_from_hebrew(year: i64, month: i64, day: i64) -> i64 {
+    let mut days = new_year(year) + day - 1;
+    if month < TISHRI {
+        for m in TISHRI..=last_month_of_year(year) {
+            days += last_day_of_month(m, year);
+        }
+        for m in NISAN..month {
+            days += last_day_of_month(m, year);
+        }
+    } else {
+        for m in TISHRI..month {
+            days += last_day_of_month(m, year);
+        }
+    }
+    days
+}
+
+/// 公历转希伯来历，返回 (年, 月, 日)，月份编号尼散月为 1
+pub fn from_gregorian(date: NaiveDate) -> (i64, u32, u32) {
+    let fixed = date.num_days_from_ce() as i64;
+    let approx = ((fixed - HEBREW_EPOCH) as f64 / (35_975_351.0 / 98_496.0)).floor() as i64 + 1;
+    let mut year = approx - 1;
+    while new_year(year + 1) <= fixed {
+        year += 1;
+    }
+    let start = if fixed < fixed_from_hebrew(year, NISAN, 1) {
+        TISHRI
+    } else {
+        NISAN
+    };
+    let mut month = start;
+    while fixed > fixed_from_hebrew(year, month, last_day_of_month(month, year)) {
+        month += 1;
+    }
+    let day = fixed - fixed_from_hebrew(year, month, 1) + 1;
+    (year, month as u32, day as u32)
+}
+
+fn month_name(month: u32, year: i64) -> &'static str {
+    match month {
+        1 => "Nisan",
+        2 => "Iyar",
+        3 => "Sivan",
+        4 => "Tammuz",
+        5 => "Av",
+        6 => "Elul",
+        7 => "Tishrei",
+        8 => "Heshvan",
+        9 => "Kislev",
+        10 => "Tevet",
+        11 => "Shevat",
+        12 if is_leap_year(year) => "Adar I",
+        12 => "Adar",
+        13 => "Adar II",
+        _ => "",
+    }
+}
+
+impl AlternateCalendar for HebrewCalendar {
+    fn date_label(&self, date: NaiveDate) -> String {
+        let (year, month, day) = from_gregorian(date);
+        if day == 1 {
+            format!("{} {}", month_name(month, year), year)
+        } else {
+            format!("{} {}", day, month_name(month, year))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_gregorian() {
+        let cases = [
+            ((2000, 1, 1), (5760, 10, 23)),
+            ((2024, 10, 3), (5785, 7, 1)),
+            ((2024, 3, 24), (5784, 13, 14)),
+            ((2023, 9, 16), (5784, 7, 1)),
+            ((2025, 4, 13), (5785, 1, 15)),
+        ];
+        for ((y, m, d), expected) in cases {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            assert_eq!(from_gregorian(date), expected, "{}", date);
+        }
+    }
+
+    #[test]
+    fn test_date_label() {
+        let calendar = HebrewCalendar;
+        let date = NaiveDate::from_ymd_opt(2024, 3, 24).unwrap();
+        assert_eq!(calendar.date_label(date), "14 Adar II");
+        let date = NaiveDate::from_ymd_opt(2024, 10, 3).unwrap();
+        assert_eq!(calendar.date_label(date), "Tishrei 5785");
+    }
+}
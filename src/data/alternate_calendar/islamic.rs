@@ -0,0 +1,75 @@
+use super::{to_julian_day_number, AlternateCalendar};
+use chrono::NaiveDate;
+
+/// 伊斯兰历（表格历，民用纪元 622-07-16）
+pub struct IslamicCalendar;
+
+/// 伊斯兰历纪元的儒略日数前一天
+const ISLAMIC_EPOCH: i64 = 1_948_439;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Muharram",
+    "Safar",
+    "Rabi I",
+    "Rabi II",
+    "Jumada I",
+    "Jumada II",
+    "Rajab",
+    "Shaban",
+    "Ramadan",
+    "Shawwal",
+    "Dhu al-Qadah",
+    "Dhu al-Hijjah",
+];
+
+fn islamic_to_jdn(year: i64, month: i64, day: i64) -> i64 {
+    // ceil(29.5 * (month - 1))
+    let month_days = (59 * (month - 1) + 1) / 2;
+    day + month_days + (year - 1) * 354 + (3 + 11 * year).div_euclid(30) + ISLAMIC_EPOCH
+}
+
+/// 公历转伊斯兰历，返回 (年, 月, 日)
+pub fn from_gregorian(date: NaiveDate) -> (i64, u32, u32) {
+    let jdn = to_julian_day_number(date);
+    let year = (30 * (jdn - ISLAMIC_EPOCH - 1) + 10646).div_euclid(10631);
+    let days_into_year = jdn - 29 - islamic_to_jdn(year, 1, 1);
+    let month = ((days_into_year as f64 / 29.5).ceil() as i64 + 1).clamp(1, 12);
+    let day = jdn - islamic_to_jdn(year, month, 1) + 1;
+    (year, month as u32, day as u32)
+}
+
+impl AlternateCalendar for IslamicCalendar {
+    fn date_label(&self, date: NaiveDate) -> String {
+        let (year, month, day) = from_gregorian(date);
+        if day == 1 {
+            format!("{} {}", MONTH_NAMES[month as usize - 1], year)
+        } else {
+            format!("{} {}", day, MONTH_NAMES[month as usize - 1])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_gregorian() {
+        let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        assert_eq!(from_gregorian(date), (1420, 9, 24));
+        // 纪元第一天 (儒略历 622-07-16 = 格里历 622-07-19)
+        let epoch = NaiveDate::from_ymd_opt(622, 7, 19).unwrap();
+        assert_eq!(from_gregorian(epoch), (1, 1, 1));
+        let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        assert_eq!(from_gregorian(date), (1446, 9, 1));
+    }
+
+    #[test]
+    fn test_date_label() {
+        let calendar = IslamicCalendar;
+        let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        assert_eq!(calendar.date_label(date), "24 Ramadan");
+        let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        assert_eq!(calendar.date_label(date), "Ramadan 1446");
+    }
+}
@@ -0,0 +1,98 @@
+pub mod buddhist;
+pub mod hebrew;
+pub mod islamic;
+pub mod persian;
+
+use buddhist::BuddhistCalendar;
+use chrono::{Datelike, NaiveDate};
+use hebrew::HebrewCalendar;
+use islamic::IslamicCalendar;
+use persian::PersianCalendar;
+use std::str::FromStr;
+
+/// 儒略日数与 chrono 公元日数（0001-01-01 为 1）之间的差值
+const JDN_CE_OFFSET: i64 = 1_721_425;
+
+/// 替代历法：为每一个公历日期提供一个次要的日期标签，
+/// 显示在日历格子中农历所在的位置
+pub trait AlternateCalendar {
+    /// 返回该公历日期在此历法下的标签，例如 "24 Ramadan"
+    fn date_label(&self, date: NaiveDate) -> String;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlternateCalendarKind {
+    Islamic,
+    Hebrew,
+    Persian,
+    Buddhist,
+}
+
+impl AlternateCalendarKind {
+    pub fn calendar(&self) -> &'static dyn AlternateCalendar {
+        match self {
+            AlternateCalendarKind::Islamic => &IslamicCalendar,
+            AlternateCalendarKind::Hebrew => &HebrewCalendar,
+            AlternateCalendarKind::Persian => &PersianCalendar,
+            AlternateCalendarKind::Buddhist => &BuddhistCalendar,
+        }
+    }
+}
+
+impl FromStr for AlternateCalendarKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "islamic" | "hijri" => Ok(AlternateCalendarKind::Islamic),
+            "hebrew" | "jewish" => Ok(AlternateCalendarKind::Hebrew),
+            "persian" | "jalali" | "solar_hijri" => Ok(AlternateCalendarKind::Persian),
+            "buddhist" | "thai" => Ok(AlternateCalendarKind::Buddhist),
+            _ => Err(format!("Unknown calendar: {}", s)),
+        }
+    }
+}
+
+/// 解析配置中的历法列表，忽略无法识别的名称
+pub fn parse_alternate_calendars(names: &[String]) -> Vec<AlternateCalendarKind> {
+    names
+        .iter()
+        .filter_map(|name| name.parse::<AlternateCalendarKind>().ok())
+        .collect()
+}
+
+/// 公历日期转儒略日数
+pub fn to_julian_day_number(date: NaiveDate) -> i64 {
+    date.num_days_from_ce() as i64 + JDN_CE_OFFSET
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_alternate_calendars() {
+        let names = vec![
+            "hijri".to_string(),
+            "Hebrew".to_string(),
+            "mayan".to_string(),
+            "jalali".to_string(),
+            "thai".to_string(),
+        ];
+        assert_eq!(
+            parse_alternate_calendars(&names),
+            vec![
+                AlternateCalendarKind::Islamic,
+                AlternateCalendarKind::Hebrew,
+                AlternateCalendarKind::Persian,
+                AlternateCalendarKind::Buddhist,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_julian_day_number() {
+        let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        assert_eq!(to_julian_day_number(date), 2_451_545);
+    }
+}
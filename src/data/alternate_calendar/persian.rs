@@ -0,0 +1,120 @@
+use super::AlternateCalendar;
+use chrono::{Datelike, NaiveDate};
+
+/// 伊朗太阳历（贾拉利历），使用 Borkowski 的闰年断点算法
+pub struct PersianCalendar;
+
+/// 闰年周期发生变化的年份
+const BREAKS: [i64; 20] = [
+    -61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210, 1635, 2060, 2097, 2192, 2262, 2324,
+    2394, 2456, 3178,
+];
+
+const MONTH_NAMES: [&str; 12] = [
+    "Farvardin",
+    "Ordibehesht",
+    "Khordad",
+    "Tir",
+    "Mordad",
+    "Shahrivar",
+    "Mehr",
+    "Aban",
+    "Azar",
+    "Dey",
+    "Bahman",
+    "Esfand",
+];
+
+/// 计算波斯历某年的信息
+/// 返回 (距离最近闰年的年数, 新年(法尔瓦丁月初一)在公历三月的日期)
+fn jal_cal(jy: i64) -> (i64, u32) {
+    let gy = jy + 621;
+    let mut leap_j = -14;
+    let mut jp = BREAKS[0];
+    let mut jump = 0;
+    for &jm in BREAKS.iter().skip(1) {
+        jump = jm - jp;
+        if jy < jm {
+            break;
+        }
+        leap_j += jump / 33 * 8 + (jump % 33) / 4;
+        jp = jm;
+    }
+    let mut n = jy - jp;
+    leap_j += n / 33 * 8 + (n % 33 + 3) / 4;
+    if jump % 33 == 4 && jump - n == 4 {
+        leap_j += 1;
+    }
+    let leap_g = gy / 4 - (gy / 100 + 1) * 3 / 4 - 150;
+    let march = 20 + leap_j - leap_g;
+    if jump - n < 6 {
+        n = n - jump + (jump + 4) / 33 * 33;
+    }
+    let mut leap = ((n + 1) % 33 - 1) % 4;
+    if leap == -1 {
+        leap = 4;
+    }
+    (leap, march as u32)
+}
+
+/// 公历转波斯历，返回 (年, 月, 日)
+pub fn from_gregorian(date: NaiveDate) -> (i64, u32, u32) {
+    let gy = date.year() as i64;
+    let mut jy = gy - 621;
+    let (leap, march) = jal_cal(jy);
+    let new_year = NaiveDate::from_ymd_opt(gy as i32, 3, march).unwrap();
+    let mut k = (date - new_year).num_days();
+    if k >= 0 {
+        if k <= 185 {
+            return (jy, 1 + (k / 31) as u32, (k % 31) as u32 + 1);
+        }
+        k -= 186;
+    } else {
+        jy -= 1;
+        k += 179;
+        if leap == 1 {
+            k += 1;
+        }
+    }
+    (jy, 7 + (k / 30) as u32, (k % 30) as u32 + 1)
+}
+
+impl AlternateCalendar for PersianCalendar {
+    fn date_label(&self, date: NaiveDate) -> String {
+        let (year, month, day) = from_gregorian(date);
+        if day == 1 {
+            format!("{} {}", MONTH_NAMES[month as usize - 1], year)
+        } else {
+            format!("{} {}", day, MONTH_NAMES[month as usize - 1])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_gregorian() {
+        let cases = [
+            ((2000, 1, 1), (1378, 10, 11)),
+            ((2024, 3, 20), (1403, 1, 1)),
+            ((2025, 3, 21), (1404, 1, 1)),
+            ((2025, 3, 20), (1403, 12, 30)),
+            ((2024, 9, 22), (1403, 7, 1)),
+        ];
+        for ((y, m, d), expected) in cases {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            assert_eq!(from_gregorian(date), expected, "{}", date);
+        }
+    }
+
+    #[test]
+    fn test_date_label() {
+        let calendar = PersianCalendar;
+        let date = NaiveDate::from_ymd_opt(2025, 3, 21).unwrap();
+        assert_eq!(calendar.date_label(date), "Farvardin 1404");
+        let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        assert_eq!(calendar.date_label(date), "11 Dey");
+    }
+}
@@ -1,29 +1,42 @@
-use crate::holiday::modal::{parse_holidays_of_year, Holiday, HolidayOfYearList};
+use crate::config::model::OverlayCountry;
+use crate::data::alternate_calendar::AlternateCalendarKind;
+use crate::holiday::modal::{Holiday, HolidayOfYearList};
 use crate::holiday::utils::get_ylc_code;
-use chrono::{Datelike, Duration, Local, NaiveDate};
-use ratatui::widgets::Wrap;
+use chrono::{Datelike, Local, NaiveDate};
+use serde::{Serialize, Serializer};
 use std::collections::HashMap;
-use std::fmt::format;
-use std::mem::offset_of;
-use std::ops::Add;
 use tyme4rs::tyme::solar::SolarDay;
 
 // 表示日历中的一天
-#[derive(Debug, Clone)]
+// 序列化时只输出日期本身的数据，界面相关的字段 (是否今天、是否选中等) 不输出
+#[derive(Debug, Clone, Serialize)]
 pub struct CalendarDay {
     pub year: u32,
     pub month: u32, // 1-12
     pub day: u32,
-    pub day_of_week: u32,       // 0=Sunday, 6=Saturday
+    pub day_of_week: u32, // 0=Sunday, 6=Saturday
+    #[serde(skip)]
     pub is_current_month: bool, // 是否属于当前月份
-    pub lunar_month: i32,       // 农历月份
-    pub lunar_day: i32,         // 农历日期
+    #[serde(serialize_with = "serialize_lunar_month")]
+    pub lunar_month: i32, // 农历月份，闰月为负数
+    pub lunar_day: i32,   // 农历日期
+    #[serde(skip)]
     pub is_today: bool,
+    #[serde(skip)]
     pub is_select_day: bool,
     pub holidays: Option<Vec<Holiday>>,
+    /// 叠加国家的节假日，顺序与配置中的 overlay_countries 一致
+    #[serde(skip)]
+    pub overlay_holidays: Vec<Option<Vec<Holiday>>>,
+}
+
+/// 闰月单独用 is_leap_lunar_month 表示，月份只输出正数
+fn serialize_lunar_month<S: Serializer>(month: &i32, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i32(month.abs())
 }
 
 impl CalendarDay {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         year: u32,
         month: u32,
@@ -51,9 +64,27 @@ impl CalendarDay {
             lunar_day,
             is_today,
             is_select_day,
-            holidays: holidays.map(|holidays_ref| holidays_ref.clone()),
+            holidays: holidays.cloned(),
+            overlay_holidays: vec![],
         }
     }
+
+    pub fn is_leap_lunar_month(&self) -> bool {
+        self.lunar_month < 0
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year as i32, self.month, self.day).unwrap()
+    }
+
+    /// 按配置的顺序返回各替代历法的日期标签
+    pub fn alternate_labels(&self, calendars: &[AlternateCalendarKind]) -> Vec<String> {
+        let date = self.date();
+        calendars
+            .iter()
+            .map(|kind| kind.calendar().date_label(date))
+            .collect()
+    }
 }
 
 pub fn get_iso_data_str(year: u32, month: u32, day: u32) -> String {
@@ -86,6 +117,34 @@ impl MonthCalendar {
         }
     }
 
+    /// 为每一天填充叠加国家的节假日
+    pub fn attach_overlays(
+        &mut self,
+        holiday_map: &HashMap<String, HolidayOfYearList>,
+        overlays: &[OverlayCountry],
+    ) {
+        let mut date_maps: HashMap<String, HashMap<String, Vec<Holiday>>> = HashMap::new();
+        for day in self.day_data.iter_mut().flatten() {
+            day.overlay_holidays = overlays
+                .iter()
+                .map(|overlay| {
+                    let ylc_key =
+                        get_ylc_code(&day.year.to_string(), &overlay.language, &overlay.country);
+                    if !date_maps.contains_key(&ylc_key) {
+                        let date_map = holiday_map
+                            .get(&ylc_key)
+                            .map(|h| h.to_holiday_map())
+                            .unwrap_or_default();
+                        date_maps.insert(ylc_key.clone(), date_map);
+                    }
+                    date_maps[&ylc_key]
+                        .get(&get_iso_data_str(day.year, day.month, day.day))
+                        .cloned()
+                })
+                .collect();
+        }
+    }
+
     fn generate_calendar_data(
         year: u32,
         month: u32,
@@ -132,7 +191,7 @@ impl MonthCalendar {
                         language,
                         country,
                     ))
-                    .map(|h| {
+                    .and_then(|h| {
                         let holiday_map = h.to_holiday_map();
                         holiday_map
                             .get(&get_iso_data_str(
@@ -141,13 +200,12 @@ impl MonthCalendar {
                                 day,
                             ))
                             .cloned()
-                    })
-                    .flatten();
+                    });
                 current_week.push(CalendarDay::new(
                     prev_month_last_day.year() as u32,
                     prev_month_last_day.month(),
                     day,
-                    i as u32,
+                    i,
                     is_now_in_prev_month && day == now.day(),
                     false,
                     select_day,
@@ -167,7 +225,7 @@ impl MonthCalendar {
                     language,
                     country,
                 ))
-                .map(|h| {
+                .and_then(|h| {
                     let holiday_map = h.to_holiday_map();
                     holiday_map
                         .get(&get_iso_data_str(
@@ -176,8 +234,7 @@ impl MonthCalendar {
                             day,
                         ))
                         .cloned()
-                })
-                .flatten();
+                });
             current_week.push(CalendarDay::new(
                 prev_month_last_day.year() as u32,
                 prev_month_last_day.month(),
@@ -195,13 +252,12 @@ impl MonthCalendar {
             let day_of_week = (first_weekday as u32 + day - 1) % 7;
             let holidays = holiday_map
                 .get(&get_ylc_code(&year.to_string(), language, country))
-                .map(|h| {
+                .and_then(|h| {
                     let holiday_map = h.to_holiday_map();
                     holiday_map
                         .get(&get_iso_data_str(year, month, day))
                         .cloned()
-                })
-                .flatten();
+                });
             current_week.push(CalendarDay::new(
                 year,
                 month,
@@ -233,7 +289,7 @@ impl MonthCalendar {
                         language,
                         country,
                     ))
-                    .map(|h| {
+                    .and_then(|h| {
                         let holiday_map = h.to_holiday_map();
                         holiday_map
                             .get(&get_iso_data_str(
@@ -242,8 +298,7 @@ impl MonthCalendar {
                                 next_day,
                             ))
                             .cloned()
-                    })
-                    .flatten();
+                    });
                 let day_of_week = (current_week.len() as u32) % 7;
                 current_week.push(CalendarDay::new(
                     next_month_first_day.year() as u32,
@@ -272,7 +327,6 @@ mod tests {
 
     #[test]
     fn test_calendar_day_creation() {
-        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
         let day = CalendarDay::new(2024, 3, 15, 5, false, true, Local::now().date_naive(), None);
         assert_eq!(day.year, 2024);
         assert_eq!(day.month, 3);
@@ -281,9 +335,21 @@ mod tests {
         assert!(day.is_current_month);
     }
 
+    #[test]
+    fn test_calendar_day_alternate_labels() {
+        let day = CalendarDay::new(2000, 1, 1, 6, false, true, Local::now().date_naive(), None);
+        let labels = day.alternate_labels(&[
+            AlternateCalendarKind::Islamic,
+            AlternateCalendarKind::Hebrew,
+            AlternateCalendarKind::Persian,
+            AlternateCalendarKind::Buddhist,
+        ]);
+        assert_eq!(labels, vec!["24 Ramadan", "23 Tevet", "11 Dey", "1/1/2543"]);
+    }
+
     #[test]
     fn test_month_calendar_creation() {
-        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+        let holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
         let calendar = MonthCalendar::new(
             2024,
             3,
@@ -299,7 +365,7 @@ mod tests {
 
     #[test]
     fn test_month_calendar_weeks() {
-        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+        let holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
         let calendar = MonthCalendar::new(
             2024,
             3,
@@ -322,7 +388,7 @@ mod tests {
 
     #[test]
     fn test_month_calendar_days() {
-        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+        let holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
         let calendar = MonthCalendar::new(
             2024,
             3,
@@ -355,7 +421,7 @@ mod tests {
 
     #[test]
     fn test_month_calendar_weekdays() {
-        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+        let holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
         let calendar = MonthCalendar::new(
             2024,
             3,
@@ -385,7 +451,7 @@ mod tests {
 
     #[test]
     fn test_month_calendar_adjacent_months() {
-        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+        let holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
         let calendar = MonthCalendar::new(
             2024,
             3,
@@ -415,7 +481,7 @@ mod tests {
     #[test]
     fn test_april_30_in_may_calendar() {
         // 测试2025年5月份日历中的4月30日
-        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+        let holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
         let calendar = MonthCalendar::new(
             2025,
             5,
@@ -463,7 +529,7 @@ mod tests {
         ];
 
         for (year, month) in test_cases {
-            let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+            let holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
             let calendar = MonthCalendar::new(
                 year,
                 month,
@@ -485,7 +551,7 @@ mod tests {
 
     #[test]
     fn test_calendar_week_structure() {
-        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+        let holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
         let calendar = MonthCalendar::new(
             2024,
             3,
@@ -519,7 +585,7 @@ mod tests {
 
     #[test]
     fn test_calendar_month_transition() {
-        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+        let holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
         let calendar = MonthCalendar::new(
             2024,
             3,
@@ -548,4 +614,46 @@ mod tests {
         assert!(found_current_month, "应该包含当前月份的日期");
         assert!(found_next_month, "应该包含下个月的日期");
     }
+
+    #[test]
+    fn test_attach_overlays() {
+        use crate::holiday::embedded::load_embedded_holidays;
+        use ratatui::style::Color;
+
+        let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
+        holiday_map.insert(
+            get_ylc_code("2026", "en", "de"),
+            load_embedded_holidays("2026", "en", "de").unwrap(),
+        );
+        let overlays = vec![OverlayCountry {
+            country: "de".to_string(),
+            language: "en".to_string(),
+            marker: "DE".to_string(),
+            color: Color::Cyan,
+        }];
+        let mut calendar = MonthCalendar::new(
+            2026,
+            10,
+            NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            &holiday_map,
+            "zh",
+            "cn",
+        );
+        calendar.attach_overlays(&holiday_map, &overlays);
+        let unity_day = calendar
+            .day_data
+            .iter()
+            .flatten()
+            .find(|day| day.month == 10 && day.day == 3)
+            .unwrap();
+        let holidays = unity_day.overlay_holidays[0].as_ref().unwrap();
+        assert_eq!(holidays[0].name, "German Unity Day");
+        let other_day = calendar
+            .day_data
+            .iter()
+            .flatten()
+            .find(|day| day.month == 10 && day.day == 5)
+            .unwrap();
+        assert!(other_day.overlay_holidays[0].is_none());
+    }
 }
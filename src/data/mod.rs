@@ -1 +1,5 @@
+pub mod almanac;
+pub mod alternate_calendar;
 pub mod calendar;
+pub mod moon_phase;
+pub mod period;
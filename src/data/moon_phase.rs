@@ -0,0 +1,174 @@
+use chrono::{Duration, FixedOffset, NaiveDate, NaiveDateTime};
+use std::str::FromStr;
+
+/// 2000-01-01 12:00 UTC (J2000.0) 的儒略日
+const J2000: f64 = 2_451_545.0;
+/// 1970-01-01 00:00 UTC 的儒略日
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoonPhase {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoonPhaseGlyph {
+    Nerd,
+    Ascii,
+}
+
+impl FromStr for MoonPhaseGlyph {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "nerd" => Ok(MoonPhaseGlyph::Nerd),
+            "ascii" => Ok(MoonPhaseGlyph::Ascii),
+            _ => Err(format!("Unknown moon phase glyph: {}", s)),
+        }
+    }
+}
+
+impl MoonPhase {
+    pub fn glyph(&self, glyph: MoonPhaseGlyph) -> &'static str {
+        match glyph {
+            MoonPhaseGlyph::Nerd => match self {
+                MoonPhase::NewMoon => "\u{e38d}",
+                MoonPhase::WaxingCrescent => "\u{e390}",
+                MoonPhase::FirstQuarter => "\u{e394}",
+                MoonPhase::WaxingGibbous => "\u{e397}",
+                MoonPhase::FullMoon => "\u{e39b}",
+                MoonPhase::WaningGibbous => "\u{e39e}",
+                MoonPhase::LastQuarter => "\u{e3a2}",
+                MoonPhase::WaningCrescent => "\u{e3a5}",
+            },
+            MoonPhaseGlyph::Ascii => match self {
+                MoonPhase::NewMoon => "o",
+                MoonPhase::WaxingCrescent => ")",
+                MoonPhase::FirstQuarter => "D",
+                MoonPhase::WaxingGibbous => "0",
+                MoonPhase::FullMoon => "O",
+                MoonPhase::WaningGibbous => "0",
+                MoonPhase::LastQuarter => "C",
+                MoonPhase::WaningCrescent => "(",
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MoonPhaseInfo {
+    pub phase: MoonPhase,
+    /// 被照亮的比例，0-100
+    pub illumination: u8,
+}
+
+fn julian_day(time: NaiveDateTime) -> f64 {
+    time.and_utc().timestamp() as f64 / 86400.0 + UNIX_EPOCH_JD
+}
+
+/// 月球相对太阳的距角（0-360 度，0 为新月，180 为满月）
+/// 使用 Meeus《天文算法》第 48 章的低精度公式
+fn elongation(time: NaiveDateTime) -> f64 {
+    let t = (julian_day(time) - J2000) / 36525.0;
+    let d = (297.850_192_1 + 445_267.111_403_4 * t).to_radians();
+    let m = (357.529_109_2 + 35_999.050_290_9 * t).to_radians();
+    let m1 = (134.963_396_4 + 477_198.867_505_5 * t).to_radians();
+    let phase_angle = 180.0 - d.to_degrees() - 6.289 * m1.sin() + 2.100 * m.sin()
+        - 1.274 * (2.0 * d - m1).sin()
+        - 0.658 * (2.0 * d).sin()
+        - 0.214 * (2.0 * m1).sin()
+        - 0.110 * d.sin();
+    (180.0 - phase_angle).rem_euclid(360.0)
+}
+
+fn illumination(elongation: f64) -> u8 {
+    ((1.0 - elongation.to_radians().cos()) / 2.0 * 100.0).round() as u8
+}
+
+/// 计算某一天（按 `utc_offset` 所在时区）的月相。
+/// 如果主要月相的时刻落在这一天之内，返回该主要月相，否则返回当天正午所处的月相区间。
+pub fn moon_phase_of_day(date: NaiveDate, utc_offset: FixedOffset) -> MoonPhaseInfo {
+    let offset = Duration::seconds(utc_offset.local_minus_utc() as i64);
+    let day_start = date.and_hms_opt(0, 0, 0).unwrap() - offset;
+    let start = elongation(day_start);
+    let end = elongation(day_start + Duration::days(1));
+    let noon = elongation(day_start + Duration::hours(12));
+
+    // 一天之内距角增加约 12 度，end 小于 start 说明跨过了 0 度
+    let unwrapped_end = if end < start { end + 360.0 } else { end };
+    let principal = [
+        (0.0, MoonPhase::NewMoon),
+        (90.0, MoonPhase::FirstQuarter),
+        (180.0, MoonPhase::FullMoon),
+        (270.0, MoonPhase::LastQuarter),
+        (360.0, MoonPhase::NewMoon),
+    ]
+    .iter()
+    .find(|(angle, _)| start <= *angle && *angle < unwrapped_end)
+    .map(|(_, phase)| *phase);
+
+    let phase = principal.unwrap_or(match noon {
+        e if e < 90.0 => MoonPhase::WaxingCrescent,
+        e if e < 180.0 => MoonPhase::WaxingGibbous,
+        e if e < 270.0 => MoonPhase::WaningGibbous,
+        _ => MoonPhase::WaningCrescent,
+    });
+
+    MoonPhaseInfo {
+        phase,
+        illumination: illumination(noon),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase_of(y: i32, m: u32, d: u32) -> MoonPhaseInfo {
+        moon_phase_of_day(
+            NaiveDate::from_ymd_opt(y, m, d).unwrap(),
+            FixedOffset::east_opt(0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_principal_phases() {
+        // 2024-01: 下弦 4 日, 新月 11 日, 上弦 18 日, 满月 25 日 (UTC)
+        assert_eq!(phase_of(2024, 1, 4).phase, MoonPhase::LastQuarter);
+        assert_eq!(phase_of(2024, 1, 11).phase, MoonPhase::NewMoon);
+        assert_eq!(phase_of(2024, 1, 18).phase, MoonPhase::FirstQuarter);
+        assert_eq!(phase_of(2024, 1, 25).phase, MoonPhase::FullMoon);
+    }
+
+    #[test]
+    fn test_intermediate_phases() {
+        assert_eq!(phase_of(2024, 1, 14).phase, MoonPhase::WaxingCrescent);
+        assert_eq!(phase_of(2024, 1, 21).phase, MoonPhase::WaxingGibbous);
+        assert_eq!(phase_of(2024, 1, 28).phase, MoonPhase::WaningGibbous);
+        assert_eq!(phase_of(2024, 1, 8).phase, MoonPhase::WaningCrescent);
+    }
+
+    #[test]
+    fn test_illumination() {
+        assert!(phase_of(2024, 1, 25).illumination >= 98);
+        assert!(phase_of(2024, 1, 11).illumination <= 2);
+        let first_quarter = phase_of(2024, 1, 18).illumination;
+        assert!((40..=60).contains(&first_quarter));
+    }
+
+    #[test]
+    fn test_utc_offset() {
+        // 满月时刻为 2024-01-25 17:54 UTC，在 UTC+8 已经是 26 日凌晨
+        let date = NaiveDate::from_ymd_opt(2024, 1, 26).unwrap();
+        let info = moon_phase_of_day(date, FixedOffset::east_opt(8 * 3600).unwrap());
+        assert_eq!(info.phase, MoonPhase::FullMoon);
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{Datelike, NaiveDate};
+use tyme4rs::tyme::solar::SolarDay;
+use tyme4rs::tyme::Culture;
+
+/// 传统时段标记：三伏、数九、梅雨
+/// 时段的第一天显示名称（如 "初伏"、"一九"、"入梅"），之后显示 "二九第3天" 这样的计数
+pub fn period_markers(date: NaiveDate) -> Vec<String> {
+    let solar_day = SolarDay::from_ymd(
+        date.year() as isize,
+        date.month() as usize,
+        date.day() as usize,
+    );
+    let mut markers = vec![];
+
+    if let Some(dog_day) = solar_day.get_dog_day() {
+        markers.push(format_marker(
+            &dog_day.get_dog().get_name(),
+            dog_day.get_day_index(),
+        ));
+    }
+    if let Some(nine_day) = solar_day.get_nine_day() {
+        markers.push(format_marker(
+            &nine_day.get_nine().get_name(),
+            nine_day.get_day_index(),
+        ));
+    }
+    if let Some(plum_rain_day) = solar_day.get_plum_rain_day() {
+        let name = plum_rain_day.get_plum_rain().get_name();
+        // 入梅之后到出梅之前的日子按 "梅雨第N天" 计数
+        if plum_rain_day.get_day_index() == 0 {
+            markers.push(name);
+        } else {
+            markers.push(format_marker("梅雨", plum_rain_day.get_day_index()));
+        }
+    }
+
+    markers
+}
+
+fn format_marker(name: &str, day_index: usize) -> String {
+    if day_index == 0 {
+        name.to_string()
+    } else {
+        format!("{}第{}天", name, day_index + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn markers_of(y: i32, m: u32, d: u32) -> Vec<String> {
+        period_markers(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+    }
+
+    #[test]
+    fn test_nine_days() {
+        // 2024 年冬至为 12 月 21 日
+        assert_eq!(markers_of(2024, 12, 21), vec!["一九"]);
+        assert_eq!(markers_of(2024, 12, 22), vec!["一九第2天"]);
+        assert_eq!(markers_of(2024, 12, 30), vec!["二九"]);
+        assert_eq!(markers_of(2025, 1, 1), vec!["二九第3天"]);
+    }
+
+    #[test]
+    fn test_dog_days() {
+        // 2024 年初伏为 7 月 15 日，中伏 7 月 25 日
+        assert_eq!(markers_of(2024, 7, 15), vec!["初伏"]);
+        assert_eq!(markers_of(2024, 7, 16), vec!["初伏第2天"]);
+        assert_eq!(markers_of(2024, 7, 25), vec!["中伏"]);
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert!(markers_of(2024, 4, 1).is_empty());
+    }
+}
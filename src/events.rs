@@ -1,26 +1,49 @@
 use crate::holiday::modal::HolidayOfYearList;
-use crate::state::{GotoPanelState, NotificationMessage, RiqiMode, RiqiState};
+use crate::state::{
+    GotoPanelState, MeasureState, NotificationMessage, PlanLeaveState, RiqiMode, RiqiState,
+};
 use crate::utils::add_months_safe;
 use chrono::{Datelike, Duration, Local, NaiveDate};
 use crossterm::event::{Event, KeyCode, KeyEvent};
-use log::{debug, info};
 use rand::distr::{Alphanumeric, SampleString};
 use std::sync::mpsc::Sender;
-use std::thread;
 use tokio::time::sleep;
 
 // 统一的事件枚举：合并了 UI 事件和业务数据事件
 pub enum AppEvent {
+    #[allow(dead_code)]
     Quit,
     TerminalEvent(Event),
     UpdateHoliday(String, HolidayOfYearList),
+    /// 某个 year_lang_country 的节假日数据从所有数据源下载失败
+    HolidayLoadFailed(String),
+    #[allow(dead_code)]
     AddNotification(NotificationMessage),
     RemoveNotification(NotificationMessage),
 }
 
-pub fn handle_normal_mode_key_event(key: KeyEvent, riqi_state: &mut RiqiState) {
-    let now = Local::now();
+/// 显示通知，5 秒后自动移除；相同 id 的通知已经显示时不重复显示
+pub fn push_notification(
+    riqi_state: &mut RiqiState,
+    id: &str,
+    message: &str,
+    sender: Sender<AppEvent>,
+) {
+    if riqi_state.notification.iter().any(|n| n.id == id) {
+        return;
+    }
+    let notification = NotificationMessage {
+        id: id.to_string(),
+        message: message.to_string(),
+    };
+    riqi_state.notification.push(notification.clone());
+    tokio::spawn(async move {
+        sleep(tokio::time::Duration::from_secs(5)).await;
+        let _ = sender.send(AppEvent::RemoveNotification(notification));
+    });
+}
 
+pub fn handle_normal_mode_key_event(key: KeyEvent, riqi_state: &mut RiqiState) {
     if key.code == KeyCode::Char('q') || key.code == KeyCode::Esc {
         return;
     }
@@ -49,7 +72,7 @@ pub fn handle_normal_mode_key_event(key: KeyEvent, riqi_state: &mut RiqiState) {
         riqi_state.select_day = add_months_safe(riqi_state.select_day, -12);
     }
     if key.code == KeyCode::Char('t') {
-        riqi_state.select_day = now.date_naive();
+        riqi_state.select_day = Local::now().date_naive();
     }
 
     if key.code == KeyCode::Char('g') {
@@ -61,6 +84,90 @@ pub fn handle_normal_mode_key_event(key: KeyEvent, riqi_state: &mut RiqiState) {
             focus_inp: 0,
         }
     }
+    if key.code == KeyCode::Char('a') {
+        riqi_state.mode = RiqiMode::Almanac;
+    }
+    if key.code == KeyCode::Char('p') {
+        riqi_state.mode = RiqiMode::PlanLeave;
+        riqi_state.plan_leave = PlanLeaveState {
+            year: riqi_state.select_day.year(),
+            days: riqi_state.plan_leave.days,
+            plans: vec![],
+            selected: 0,
+            has_data: true,
+        };
+    }
+    if key.code == KeyCode::Char('m') {
+        riqi_state.mode = RiqiMode::Measure;
+        riqi_state.measure = MeasureState {
+            anchor: riqi_state.select_day,
+            workdays: 0,
+            next_workday: None,
+            offset: riqi_state.measure.offset,
+            offset_workday: None,
+            years_without_data: vec![],
+        };
+    }
+}
+
+/// 请假规划的最多请假天数
+const MAX_PLAN_LEAVE_DAYS: usize = 30;
+
+/// 请假规划面板：j,k 切换方案，+,- 调整请假天数，方案由主循环重新计算
+pub fn handle_plan_leave_mode_key_event(key: KeyEvent, riqi_state: &mut RiqiState) {
+    let plan_leave = &mut riqi_state.plan_leave;
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('p') => {
+            riqi_state.mode = RiqiMode::Normal;
+        }
+        KeyCode::Char('j') | KeyCode::Down if plan_leave.selected + 1 < plan_leave.plans.len() => {
+            plan_leave.selected += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            plan_leave.selected = plan_leave.selected.saturating_sub(1);
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            plan_leave.days = (plan_leave.days + 1).min(MAX_PLAN_LEAVE_DAYS);
+            plan_leave.selected = 0;
+        }
+        KeyCode::Char('-') => {
+            plan_leave.days = plan_leave.days.saturating_sub(1).max(1);
+            plan_leave.selected = 0;
+        }
+        _ => {}
+    }
+}
+
+/// 测量模式下仍然可以切换日期，[ 和 ] 跳转到上一个和下一个工作日由主循环处理；
+/// +,- 调整显示选中日期之后 (之前) 第几个工作日
+pub fn handle_measure_mode_key_event(key: KeyEvent, riqi_state: &mut RiqiState) {
+    if key.code == KeyCode::Char('q') || key.code == KeyCode::Esc || key.code == KeyCode::Char('m')
+    {
+        riqi_state.mode = RiqiMode::Normal;
+        return;
+    }
+    if key.code == KeyCode::Char('+') || key.code == KeyCode::Char('=') {
+        riqi_state.measure.offset += 1;
+        return;
+    }
+    if key.code == KeyCode::Char('-') {
+        riqi_state.measure.offset -= 1;
+        return;
+    }
+    if key.code == KeyCode::Char('a') || key.code == KeyCode::Char('g') {
+        return;
+    }
+    handle_normal_mode_key_event(key, riqi_state);
+}
+
+pub fn handle_almanac_mode_key_event(key: KeyEvent, riqi_state: &mut RiqiState) {
+    if key.code == KeyCode::Char('q') || key.code == KeyCode::Esc || key.code == KeyCode::Char('a')
+    {
+        riqi_state.mode = RiqiMode::Normal;
+        return;
+    }
+    // 黄历面板打开时仍然可以切换日期，面板内容跟随选中的日期
+    handle_normal_mode_key_event(key, riqi_state);
 }
 
 pub fn handle_goto_mode_key_event(
@@ -103,11 +210,11 @@ pub fn handle_goto_mode_key_event(
     }
     if key.code == KeyCode::Char('h') || key.code == KeyCode::Left {
         riqi_state.goto_panel.focus_inp += 2;
-        riqi_state.goto_panel.focus_inp = riqi_state.goto_panel.focus_inp % 3;
+        riqi_state.goto_panel.focus_inp %= 3;
     }
     if key.code == KeyCode::Char('l') || key.code == KeyCode::Right {
         riqi_state.goto_panel.focus_inp += 1;
-        riqi_state.goto_panel.focus_inp = riqi_state.goto_panel.focus_inp % 3;
+        riqi_state.goto_panel.focus_inp %= 3;
     }
 
     if key.code == KeyCode::Enter {
@@ -118,17 +225,7 @@ pub fn handle_goto_mode_key_event(
         );
         if parse_day.is_none() {
             let id = Alphanumeric.sample_string(&mut rand::rng(), 10);
-            riqi_state.notification.push(NotificationMessage {
-                id: id.clone(),
-                message: "invalid date".to_string(),
-            });
-            tokio::spawn(async move {
-                sleep(tokio::time::Duration::from_secs(5)).await;
-                let _ = sender.send(AppEvent::RemoveNotification(NotificationMessage {
-                    id: id.clone(),
-                    message: "invalid date".to_string(),
-                }));
-            });
+            push_notification(riqi_state, &id, "invalid date", sender);
             return;
         }
 
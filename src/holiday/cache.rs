@@ -0,0 +1,177 @@
+use crate::holiday::modal::parse_holidays_of_year;
+use chrono::{DateTime, Local};
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// 缓存目录中的一个节假日文件：holidays/{year}/{language}_{country}.json
+#[derive(Debug)]
+pub struct CacheEntry {
+    /// year_lang_country，如 2026_zh_cn
+    pub key: String,
+    pub year: i32,
+    pub path: PathBuf,
+    pub modified: Option<DateTime<Local>>,
+}
+
+impl CacheEntry {
+    /// 解析缓存文件，返回其 version
+    pub fn verify(&self) -> Result<i32> {
+        let content = fs::read_to_string(&self.path)?;
+        Ok(parse_holidays_of_year(&content)?.version)
+    }
+
+    /// 下载该文件时使用的数据源
+    pub fn source(&self) -> Option<String> {
+        fs::read_to_string(get_source_record_path(&self.path))
+            .ok()
+            .map(|source| source.trim().to_string())
+    }
+}
+
+/// 每个缓存文件旁记录其数据源，如 2026/zh_cn.source
+pub fn get_source_record_path(cache_file_path: &Path) -> PathBuf {
+    cache_file_path.with_extension("source")
+}
+
+/// 下载缓存文件时服务器返回的 ETag 和 Last-Modified，用于条件请求
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl CacheValidators {
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// 每个缓存文件旁记录其 ETag 和 Last-Modified，如 2026/zh_cn.validators
+pub fn get_validators_path(cache_file_path: &Path) -> PathBuf {
+    cache_file_path.with_extension("validators")
+}
+
+/// 读取缓存文件的数据源和条件请求头，只有同一个数据源的条件请求才有意义
+pub fn load_cache_validators(cache_file_path: &Path) -> Option<(String, CacheValidators)> {
+    let source = fs::read_to_string(get_source_record_path(cache_file_path)).ok()?;
+    let validators = fs::read_to_string(get_validators_path(cache_file_path)).ok()?;
+    let validators: CacheValidators = serde_json::from_str(&validators).ok()?;
+    (!validators.is_empty()).then(|| (source.trim().to_string(), validators))
+}
+
+/// 保存缓存文件的数据源和条件请求头，没有条件请求头时删除旧的记录
+pub fn save_cache_record(
+    cache_file_path: &Path,
+    source: &str,
+    validators: &CacheValidators,
+) -> Result<()> {
+    fs::write(get_source_record_path(cache_file_path), source)?;
+    let validators_path = get_validators_path(cache_file_path);
+    if validators.is_empty() {
+        let _ = fs::remove_file(validators_path);
+    } else {
+        fs::write(validators_path, serde_json::to_string(validators)?)?;
+    }
+    Ok(())
+}
+
+/// 服务器返回 304 时更新缓存文件的修改时间，内容和 version 保持不变
+pub fn touch_cache_file(cache_file_path: &Path) -> Result<()> {
+    fs::File::options()
+        .append(true)
+        .open(cache_file_path)?
+        .set_modified(SystemTime::now())?;
+    Ok(())
+}
+
+/// 列出缓存的节假日文件，按 key 排序
+pub fn list_cache_entries(holidays_dir: &Path) -> Vec<CacheEntry> {
+    let mut entries = vec![];
+    let Ok(year_dirs) = fs::read_dir(holidays_dir) else {
+        return entries;
+    };
+    for year_dir in year_dirs.flatten() {
+        let Some(year) = year_dir
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<i32>().ok())
+        else {
+            continue;
+        };
+        let Ok(files) = fs::read_dir(year_dir.path()) else {
+            continue;
+        };
+        for file in files.flatten() {
+            let path = file.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            let Some(lc_code) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            entries.push(CacheEntry {
+                key: format!("{}_{}", year, lc_code),
+                year,
+                modified: file
+                    .metadata()
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+                    .map(DateTime::<Local>::from),
+                path,
+            });
+        }
+    }
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+    entries
+}
+
+/// 删除早于 before 年份的缓存目录，返回被删除的年份
+pub fn prune_cache(holidays_dir: &Path, before: i32) -> Result<Vec<i32>> {
+    let mut years: Vec<i32> = list_cache_entries(holidays_dir)
+        .iter()
+        .map(|entry| entry.year)
+        .filter(|year| *year < before)
+        .collect();
+    years.dedup();
+    for year in &years {
+        fs::remove_dir_all(holidays_dir.join(year.to_string()))?;
+    }
+    Ok(years)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_and_prune_cache() {
+        let dir = std::env::temp_dir().join(format!("riqi_cache_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for year in ["2024", "2026"] {
+            fs::create_dir_all(dir.join(year)).unwrap();
+            fs::write(
+                dir.join(year).join("zh_cn.json"),
+                r#"{"version": 2, "holidays": []}"#,
+            )
+            .unwrap();
+        }
+        fs::write(dir.join("2026").join("zh_cn.source"), "gitee\n").unwrap();
+        fs::write(dir.join("2026").join("en_cn.json"), "{").unwrap();
+        fs::write(dir.join("meta.json"), "{}").unwrap();
+
+        let entries = list_cache_entries(&dir);
+        let keys: Vec<&str> = entries.iter().map(|entry| entry.key.as_str()).collect();
+        assert_eq!(keys, vec!["2024_zh_cn", "2026_en_cn", "2026_zh_cn"]);
+        assert!(entries[1].verify().is_err());
+        assert_eq!(entries[2].verify().unwrap(), 2);
+        assert_eq!(entries[2].source().as_deref(), Some("gitee"));
+        assert_eq!(entries[0].source(), None);
+
+        assert_eq!(prune_cache(&dir, 2025).unwrap(), vec![2024]);
+        assert_eq!(list_cache_entries(&dir).len(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
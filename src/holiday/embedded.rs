@@ -0,0 +1,30 @@
+use crate::holiday::modal::{parse_holidays_of_year, HolidayOfYearList};
+use crate::holiday::utils::get_lc_code;
+use include_dir::{include_dir, Dir};
+
+/// 编译时打包进二进制的节假日数据，作为离线时的兜底
+static HOLIDAYS: Dir = include_dir!("$CARGO_MANIFEST_DIR/resources/holidays");
+
+pub fn load_embedded_holidays(
+    year: &str,
+    language: &str,
+    country: &str,
+) -> Option<HolidayOfYearList> {
+    let path = format!("{}/{}.json", year, get_lc_code(language, country));
+    let content = HOLIDAYS.get_file(&path)?.contents_utf8()?;
+    parse_holidays_of_year(content).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_embedded_holidays() {
+        let holidays = load_embedded_holidays("2026", "zh", "cn").unwrap();
+        assert!(holidays.version > 0);
+        assert!(!holidays.is_generated());
+        assert!(!holidays.holidays.is_empty());
+        assert!(load_embedded_holidays("1999", "zh", "cn").is_none());
+    }
+}
@@ -0,0 +1,214 @@
+use crate::config::model::AppConfig;
+use crate::holiday::cache::CacheValidators;
+use color_eyre::eyre::bail;
+use color_eyre::Result;
+use log::{error, info};
+use reqwest::header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{Client, Proxy, StatusCode};
+use std::time::Duration;
+
+/// 第一次重试前的等待时间，之后每次翻倍
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// 条件请求的结果
+#[derive(Debug, PartialEq)]
+pub enum HttpResponse {
+    Ok {
+        body: String,
+        validators: CacheValidators,
+    },
+    /// 304，缓存仍然有效
+    NotModified,
+    /// 404，远程没有该文件
+    NotFound,
+}
+
+/// 下载节假日数据共用的 HTTP 客户端：超时、代理、User-Agent 和指数退避重试
+#[derive(Clone)]
+pub struct HttpClient {
+    client: Client,
+    retries: u32,
+}
+
+impl HttpClient {
+    pub fn new(timeout: Duration, retries: u32, proxy: Option<&str>) -> Result<Self> {
+        let mut builder = Client::builder()
+            .timeout(timeout)
+            .user_agent(concat!("riqi/", env!("CARGO_PKG_VERSION")));
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(Proxy::all(proxy)?);
+        }
+        Ok(Self {
+            client: builder.build()?,
+            retries,
+        })
+    }
+
+    /// 按配置创建，代理地址无效时忽略代理
+    pub fn from_config(app_config: &AppConfig) -> Self {
+        let timeout = Duration::from_secs(app_config.http_timeout);
+        Self::new(
+            timeout,
+            app_config.http_retries,
+            app_config.proxy.as_deref(),
+        )
+        .or_else(|err| {
+            error!("create http client fail: {}, ignore proxy", err);
+            Self::new(timeout, app_config.http_retries, None)
+        })
+        .expect("create http client")
+    }
+
+    /// 下载文本，网络错误、5xx 和 429 时重试，404 返回 NotFound，其他 4xx 直接返回错误。
+    /// 带上缓存的 If-None-Match / If-Modified-Since，服务器返回 304 时不下载内容
+    pub async fn get_conditional(
+        &self,
+        url: &str,
+        validators: Option<&CacheValidators>,
+    ) -> Result<HttpResponse> {
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.get(url);
+            if let Some(validators) = validators {
+                if let Some(etag) = &validators.etag {
+                    request = request.header(IF_NONE_MATCH, etag);
+                }
+                if let Some(last_modified) = &validators.last_modified {
+                    request = request.header(IF_MODIFIED_SINCE, last_modified);
+                }
+            }
+            let error = match request.send().await {
+                Ok(response) if response.status() == StatusCode::NOT_MODIFIED => {
+                    return Ok(HttpResponse::NotModified);
+                }
+                Ok(response) if response.status() == StatusCode::NOT_FOUND => {
+                    return Ok(HttpResponse::NotFound);
+                }
+                Ok(response) if response.status().is_success() => {
+                    let validators = get_validators(response.headers());
+                    return Ok(HttpResponse::Ok {
+                        body: response.text().await?,
+                        validators,
+                    });
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_client_error() && status != StatusCode::TOO_MANY_REQUESTS {
+                        bail!("Fail to download file: HTTP status {}", status);
+                    }
+                    format!("HTTP status {}", status)
+                }
+                Err(err) => err.to_string(),
+            };
+            if attempt >= self.retries {
+                bail!("Fail to download file: {}", error);
+            }
+            let delay = retry_delay(attempt);
+            info!("download {} fail: {}, retry in {:?}", url, error, delay);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+fn get_validators(headers: &HeaderMap) -> CacheValidators {
+    let header = |name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    };
+    CacheValidators {
+        etag: header(ETAG),
+        last_modified: header(LAST_MODIFIED),
+    }
+}
+
+fn retry_delay(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY * 2u32.pow(attempt.min(6))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(0), Duration::from_millis(500));
+        assert_eq!(retry_delay(2), Duration::from_secs(2));
+        assert_eq!(retry_delay(20), Duration::from_secs(32));
+    }
+
+    #[test]
+    fn test_invalid_proxy() {
+        assert!(HttpClient::new(Duration::from_secs(1), 0, Some("not a proxy")).is_err());
+    }
+
+    /// 本地的简易 HTTP 服务器：请求 2030 年时返回 404，If-None-Match 为 "v1" 时返回 304，
+    /// 否则返回带 ETag 的内容
+    async fn spawn_etag_server() -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 4096];
+                let n = stream.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+                let response = if request.starts_with("get /2030/") {
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                        .to_string()
+                } else if request.contains("if-none-match: \"v1\"") {
+                    "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n"
+                        .to_string()
+                } else {
+                    let body = "{}";
+                    format!(
+                        "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nLast-Modified: Mon, 19 Oct 2026 00:00:00 GMT\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                };
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        format!("http://{}/2026/zh_cn.json", addr)
+    }
+
+    #[tokio::test]
+    async fn test_conditional_request() {
+        let url = spawn_etag_server().await;
+        let client = HttpClient::new(Duration::from_secs(5), 0, None).unwrap();
+
+        let response = client.get_conditional(&url, None).await.unwrap();
+        let validators = CacheValidators {
+            etag: Some("\"v1\"".to_string()),
+            last_modified: Some("Mon, 19 Oct 2026 00:00:00 GMT".to_string()),
+        };
+        assert_eq!(
+            response,
+            HttpResponse::Ok {
+                body: "{}".to_string(),
+                validators: validators.clone(),
+            }
+        );
+
+        let response = client
+            .get_conditional(&url, Some(&validators))
+            .await
+            .unwrap();
+        assert_eq!(response, HttpResponse::NotModified);
+
+        let stale = CacheValidators {
+            etag: Some("\"v0\"".to_string()),
+            last_modified: None,
+        };
+        let response = client.get_conditional(&url, Some(&stale)).await.unwrap();
+        assert!(matches!(response, HttpResponse::Ok { .. }));
+
+        let missing = url.replace("/2026/", "/2030/");
+        let response = client.get_conditional(&missing, None).await.unwrap();
+        assert_eq!(response, HttpResponse::NotFound);
+    }
+}
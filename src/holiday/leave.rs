@@ -0,0 +1,145 @@
+use crate::holiday::workdays::WorkdayCalendar;
+use chrono::{Datelike, Days, NaiveDate};
+
+/// 计算跨年的假期时，在年份前后多看的天数
+const YEAR_MARGIN_DAYS: u64 = 14;
+
+/// 一个请假方案：请 leave_days 这几天假，start ~ end 连续休息
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeavePlan {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub leave_days: Vec<NaiveDate>,
+}
+
+impl LeavePlan {
+    /// 连续休息的天数
+    pub fn days_off(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+}
+
+/// 在 year 中找出最多请 max_leave 天假能得到的最长连续休息，按休息天数从多到少返回
+/// 互不重叠的 limit 个方案。调休上班的周末需要请假，半天假也按工作日计算
+pub fn plan_leave(
+    calendar: &WorkdayCalendar,
+    year: i32,
+    max_leave: usize,
+    limit: usize,
+) -> Vec<LeavePlan> {
+    let (Some(first), Some(last)) = (
+        NaiveDate::from_ymd_opt(year, 1, 1)
+            .and_then(|date| date.checked_sub_days(Days::new(YEAR_MARGIN_DAYS))),
+        NaiveDate::from_ymd_opt(year, 12, 31)
+            .and_then(|date| date.checked_add_days(Days::new(YEAR_MARGIN_DAYS))),
+    ) else {
+        return vec![];
+    };
+    let days: Vec<(NaiveDate, bool)> = first
+        .iter_days()
+        .take_while(|date| *date <= last)
+        .map(|date| (date, calendar.is_workday(date)))
+        .collect();
+
+    // 以每个工作日之后的一天为起点，向后延伸到再请一天假就超过 max_leave 为止
+    let mut candidates: Vec<LeavePlan> = vec![];
+    for start in 0..days.len() {
+        if start > 0 && !days[start - 1].1 {
+            continue;
+        }
+        let mut leave_days = vec![];
+        let mut end = start;
+        while end < days.len() {
+            if days[end].1 {
+                if leave_days.len() == max_leave {
+                    break;
+                }
+                leave_days.push(days[end].0);
+            }
+            end += 1;
+        }
+        // 最后一天之后的日期不知道是否放假，无法判断是否连续
+        if end == days.len() {
+            continue;
+        }
+        let rest_days = end - start - leave_days.len();
+        // 请假的日期都在这一年内，并且至少连上一天休息日
+        if !leave_days.is_empty()
+            && rest_days > 0
+            && leave_days.iter().all(|date| date.year() == year)
+        {
+            candidates.push(LeavePlan {
+                start: days[start].0,
+                end: days[end - 1].0,
+                leave_days,
+            });
+        }
+    }
+
+    // 休息天数多的优先，相同时请假少的优先
+    candidates.sort_by(|a, b| {
+        b.days_off()
+            .cmp(&a.days_off())
+            .then(a.leave_days.len().cmp(&b.leave_days.len()))
+            .then(a.start.cmp(&b.start))
+    });
+    let mut plans: Vec<LeavePlan> = vec![];
+    for candidate in candidates {
+        if plans.len() == limit {
+            break;
+        }
+        let overlaps = plans
+            .iter()
+            .any(|plan| candidate.start <= plan.end && plan.start <= candidate.end);
+        if !overlaps {
+            plans.push(candidate);
+        }
+    }
+    plans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holiday::embedded::load_embedded_holidays;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_plan_leave_without_holidays() {
+        let calendar = WorkdayCalendar::new(None, |_| None);
+        let plans = plan_leave(&calendar, 2026, 5, 1);
+        // 只有周末时，请一周的假连上前后两个周末共 9 天
+        assert_eq!(plans[0].days_off(), 9);
+        assert_eq!(plans[0].leave_days.len(), 5);
+    }
+
+    #[test]
+    fn test_plan_leave_with_make_up_workdays() {
+        let calendar = WorkdayCalendar::new(None, |year| {
+            load_embedded_holidays(&year.to_string(), "zh", "cn").map(|list| list.to_holiday_map())
+        });
+        let plans = plan_leave(&calendar, 2026, 3, 3);
+        assert_eq!(plans.len(), 3);
+        for plan in &plans {
+            assert!(plan.leave_days.len() <= 3);
+            // 请假的日期都是工作日，其余日期都是休息日
+            for day in plan.start.iter_days().take_while(|day| *day <= plan.end) {
+                assert_eq!(calendar.is_workday(day), plan.leave_days.contains(&day));
+            }
+        }
+        // 请 9-28 ~ 9-30 连上中秋和国庆，9-25 ~ 10-07 共 13 天
+        assert_eq!(
+            plans[0],
+            LeavePlan {
+                start: date(9, 25),
+                end: date(10, 7),
+                leave_days: vec![date(9, 28), date(9, 29), date(9, 30)],
+            }
+        );
+        // 春节前调休上班的 2-14 (周六) 也需要请假
+        assert!(plans[1].leave_days.contains(&date(2, 14)));
+    }
+}
@@ -1,24 +1,38 @@
 use crate::config::model::Source;
 use crate::events::AppEvent;
+use crate::holiday::cache::{
+    load_cache_validators, save_cache_record, touch_cache_file, CacheValidators,
+};
+use crate::holiday::embedded::load_embedded_holidays;
+use crate::holiday::http::{HttpClient, HttpResponse};
+use crate::holiday::meta::{
+    get_meta_cache_path, load_cached_meta, parse_holiday_meta, plan_remote_sources, RemotePlan,
+    SourceMeta,
+};
 use crate::holiday::modal::{parse_holidays_of_year, HolidayOfYearList};
-use crate::holiday::utils::{get_holiday_cache_file_path, get_ylc_code};
-use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
-use color_eyre::eyre::{bail, OptionExt};
+use crate::holiday::rules::generate_holidays_from_rules;
+use crate::holiday::source::{
+    fetch_source_file, get_holiday_data_file_url, get_holiday_meta_file_url,
+};
+use crate::holiday::utils::{get_holiday_cache_file_path, get_ylc_code, parse_ylc_code};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use color_eyre::eyre::bail;
 use color_eyre::Result;
 use log::{error, info};
-use std::io::Bytes;
 use std::sync::mpsc::Sender;
-use std::thread::sleep;
 use std::{collections::HashMap, sync::Arc};
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, OnceCell};
 
 pub enum LoadRemoteState {
     None,
     Loading,
     Finish,
+    /// 所有数据源都下载失败，可以按 r 重试
     Failed,
+    /// meta.json 中没有该文件，远程不存在，不再请求
+    Missing,
 }
 
 pub struct HolidayManagerProperty {
@@ -27,38 +41,21 @@ pub struct HolidayManagerProperty {
 
 pub struct YlcHolidayUpdateState {
     loaded_local_cache: bool,
-    local_cache_time: Option<NaiveDate>,
+    loaded_embedded: bool,
     load_remote_state: LoadRemoteState,
+    /// 本地缓存的 (修改时间, version)，重试时使用
+    cache: Option<(NaiveDateTime, i32)>,
 }
 
 #[derive(Clone)]
 pub struct HolidayManager {
     property: Arc<Mutex<HolidayManagerProperty>>,
+    /// 每次启动只下载一次 meta.json，失败时为上次缓存的 meta
+    meta: Arc<OnceCell<Option<SourceMeta>>>,
+    http_client: HttpClient,
     tx: Sender<AppEvent>,
 }
 
-pub fn get_holiday_data_file_url(
-    year: &str,
-    language: &str,
-    country: &str,
-    source: &Source,
-) -> String {
-    match source {
-        Source::Github => format!(
-            "https://raw.githubusercontent.com/alinGmail/riqi/refs/heads/main/resources/holidays/{}/{}_{}.json",
-            year,
-            language,
-            country
-        ),
-        Source::Gitee => format!(
-            "https://gitee.com/zhaixiaolin/riqi/raw/main/resources/holidays/{}/{}_{}.json",
-            year,
-            language,
-            country
-        ),
-    }
-}
-
 pub fn is_need_update(modify_time: NaiveDateTime) -> bool {
     // 1. 获取当前的 UTC NaiveDateTime
     let now = Utc::now().naive_utc();
@@ -70,10 +67,12 @@ pub async fn save_holidays_file(
     year: &str,
     language: &str,
     country: &str,
+    source: &Source,
+    validators: &CacheValidators,
     content: &[u8],
 ) -> Result<()> {
     let path = get_holiday_cache_file_path(year, language, country);
-    if path == None {
+    if path.is_none() {
         bail!("get holiday cache file path failed")
     }
     let path_unwrap = path.unwrap();
@@ -86,6 +85,8 @@ pub async fn save_holidays_file(
     // 3. 写入文件
     let mut file = fs::File::create(&path_unwrap).await?;
     file.write_all(content).await?;
+    // 记录数据源和条件请求头，供 riqi holidays cache list 显示和下次条件请求使用
+    save_cache_record(&path_unwrap, source.as_str(), validators)?;
     info!(
         "Successfully downloaded and saved file to {}",
         path_unwrap.display()
@@ -93,25 +94,110 @@ pub async fn save_holidays_file(
     Ok(())
 }
 
-pub async fn download_file(url: &str) -> Result<String> {
-    let response = reqwest::get(url).await?;
-    if !response.status().is_success() {
-        error!("Fail to download file: HTTP status {}", response.status());
-        bail!("Fail to download file: HTTP status {}", response.status())
+pub enum FetchHolidays<'a> {
+    Downloaded {
+        content: String,
+        holidays: HolidayOfYearList,
+        source: &'a Source,
+        validators: CacheValidators,
+    },
+    /// 服务器返回 304，本地缓存仍是最新
+    NotModified,
+    /// 所有数据源都返回 404，远程还没有这一年的数据
+    NotFound,
+}
+
+/// 按优先级依次尝试数据源，返回第一个成功的结果，所有数据源都是 404 时返回 NotFound。
+/// cached 为缓存文件的 (数据源, 条件请求头)，只对同一个数据源发送条件请求；
+/// 有 meta 时，来自 meta 所在数据源的文件需要与 meta 中的校验和一致
+pub async fn fetch_holidays_from_sources<'a>(
+    client: &HttpClient,
+    year: &str,
+    language: &str,
+    country: &str,
+    sources: impl IntoIterator<Item = &'a Source>,
+    cached: Option<&(String, CacheValidators)>,
+    meta: Option<&SourceMeta>,
+) -> Option<FetchHolidays<'a>> {
+    let ylc_key = get_ylc_code(year, language, country);
+    let (mut tried, mut not_found) = (0, 0);
+    for source in sources {
+        tried += 1;
+        let url = get_holiday_data_file_url(year, language, country, source);
+        info!("remote url is {} (source: {})", &url, source.as_str());
+        let validators = cached
+            .filter(|(cached_source, _)| cached_source == source.as_str())
+            .map(|(_, validators)| validators);
+        match fetch_source_file(client, &url, validators).await {
+            Ok(HttpResponse::Ok { body: content, .. })
+                if meta.is_some_and(|meta| !meta.verify_checksum(source, &ylc_key, &content)) =>
+            {
+                error!("{} checksum mismatch with meta.json", &url);
+            }
+            Ok(HttpResponse::Ok {
+                body: content,
+                validators,
+            }) => match parse_holidays(&content) {
+                Ok(holidays) => {
+                    return Some(FetchHolidays::Downloaded {
+                        content,
+                        holidays,
+                        source,
+                        validators,
+                    })
+                }
+                Err(err) => error!("parse {} fail: {}", &url, err),
+            },
+            Ok(HttpResponse::NotModified) => {
+                info!("{} not modified", &url);
+                return Some(FetchHolidays::NotModified);
+            }
+            Ok(HttpResponse::NotFound) => {
+                info!("{} not found", &url);
+                not_found += 1;
+            }
+            Err(err) => error!("load {} fail: {}", &url, err),
+        }
     }
-    let content = response.bytes().await?;
-    Ok(String::from_utf8(content.into()).expect("Found invalid utf-8"))
+    (tried > 0 && not_found == tried).then_some(FetchHolidays::NotFound)
 }
+
 pub fn parse_holidays(json_str: &str) -> Result<HolidayOfYearList, serde_json::Error> {
     serde_json::from_str(json_str)
 }
 
+/// 不联网读取节假日数据，供子命令使用：缓存和内置数据中 version 较新的一份，
+/// 都没有时使用规则生成的数据
+pub fn load_offline_holidays(
+    year: &str,
+    language: &str,
+    country: &str,
+) -> Option<HolidayOfYearList> {
+    load_local_holidays(year, language, country)
+        .or_else(|| generate_holidays_from_rules(year, language, country))
+}
+
+/// 缓存和内置数据中 version 较新的一份，不使用规则生成的数据 (规则无法推算调休)
+pub fn load_local_holidays(year: &str, language: &str, country: &str) -> Option<HolidayOfYearList> {
+    let cached = get_holiday_cache_file_path(year, language, country)
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| parse_holidays_of_year(&content).ok());
+    let embedded = load_embedded_holidays(year, language, country);
+    match (cached, embedded) {
+        (Some(cached), Some(embedded)) if embedded.version > cached.version => Some(embedded),
+        (Some(cached), _) => Some(cached),
+        (None, embedded) => embedded,
+    }
+}
+
 impl HolidayManager {
-    pub fn new(tx: Sender<AppEvent>) -> Self {
+    pub fn new(tx: Sender<AppEvent>, http_client: HttpClient) -> Self {
         Self {
             property: Arc::new(Mutex::new(HolidayManagerProperty {
                 ylc_holiday_update_state: HashMap::new(),
             })),
+            meta: Arc::new(OnceCell::new()),
+            http_client,
             tx,
         }
     }
@@ -156,17 +242,112 @@ impl HolidayManager {
         }
     }
 
+    /// 发送二进制内置的节假日数据，主线程按 version 只保留更新的版本，
+    /// 所以较新的缓存或远程数据不会被内置数据覆盖。
+    /// 既没有缓存也没有内置数据时，使用规则生成的数据 (version 0)；
+    /// 连规则也没有时发送空列表 (version 0)，主线程才能在上面合并用户的调整
+    pub fn load_embedded(
+        ylc_update_state: &mut YlcHolidayUpdateState,
+        year: &str,
+        language: &str,
+        country: &str,
+        has_cache: bool,
+        tx_sender: Sender<AppEvent>,
+    ) -> Result<()> {
+        if ylc_update_state.loaded_embedded {
+            return Ok(());
+        }
+        let holiday_year_list = load_embedded_holidays(year, language, country).or_else(|| {
+            if has_cache {
+                None
+            } else {
+                generate_holidays_from_rules(year, language, country).or(Some(HolidayOfYearList {
+                    version: 0,
+                    holidays: vec![],
+                }))
+            }
+        });
+        if let Some(holiday_year_list) = holiday_year_list {
+            tx_sender.send(AppEvent::UpdateHoliday(
+                get_ylc_code(year, language, country),
+                holiday_year_list,
+            ))?;
+        }
+        ylc_update_state.loaded_embedded = true;
+        Ok(())
+    }
+
+    /// 按顺序从数据源读取 meta.json 并保存到缓存目录，全部失败时读取上次的缓存。
+    /// 缓存的 meta.json 来自同一个数据源时发送条件请求，304 时直接使用缓存
+    pub async fn load_meta(client: &HttpClient, sources: &[Source]) -> Option<SourceMeta> {
+        let meta_path = get_meta_cache_path();
+        let cached = meta_path.as_deref().and_then(load_cache_validators);
+        for source in sources {
+            let Some(url) = get_holiday_meta_file_url(source) else {
+                continue;
+            };
+            info!("meta url is {} (source: {})", &url, source.as_str());
+            let validators = cached
+                .as_ref()
+                .filter(|(cached_source, _)| cached_source == source.as_str())
+                .map(|(_, validators)| validators);
+            let (content, validators) = match fetch_source_file(client, &url, validators).await {
+                Ok(HttpResponse::Ok { body, validators }) => (body, validators),
+                Ok(HttpResponse::NotModified) => {
+                    if let Some(meta) = load_cached_meta() {
+                        info!("meta not modified, use cached meta");
+                        return Some(SourceMeta {
+                            source: source.as_str().to_string(),
+                            meta,
+                        });
+                    }
+                    continue;
+                }
+                Ok(HttpResponse::NotFound) => {
+                    info!("meta not found in {}", source.as_str());
+                    continue;
+                }
+                Err(err) => {
+                    error!("load meta from {} fail: {}", source.as_str(), err);
+                    continue;
+                }
+            };
+            let Ok(meta) = parse_holiday_meta(&content) else {
+                error!("parse meta from {} fail", source.as_str());
+                continue;
+            };
+            if let Some(path) = &meta_path {
+                if let Some(parent) = path.parent() {
+                    let _ = fs::create_dir_all(parent).await;
+                }
+                if let Err(err) = fs::write(path, content).await {
+                    error!("save meta file fail: {}", err);
+                }
+                if let Err(err) = save_cache_record(path, source.as_str(), &validators) {
+                    error!("save meta validators fail: {}", err);
+                }
+            }
+            return Some(SourceMeta {
+                source: source.as_str().to_string(),
+                meta,
+            });
+        }
+        error!("load remote meta fail, use cached meta");
+        // 缓存的 meta 只对下载它的数据源有效
+        let (source, _) = cached?;
+        load_cached_meta().map(|meta| SourceMeta { source, meta })
+    }
+
     pub async fn load_remote_file(
-        property: Arc<Mutex<HolidayManagerProperty>>,
+        &self,
         year: &str,
         language: &str,
         country: &str,
-        source: Source,
-        tx: Sender<AppEvent>,
-        old_version: Option<i32>,
+        sources: Vec<Source>,
+        cache: Option<(NaiveDateTime, i32)>,
     ) -> Result<()> {
         {
-            let mut property = property.lock().await;
+            let mut property = self.property.lock().await;
             let ylc_update_state = property
                 .ylc_holiday_update_state
                 .get_mut(&get_ylc_code(year, language, country))
@@ -178,37 +359,141 @@ impl HolidayManager {
             ylc_update_state.load_remote_state = LoadRemoteState::Loading;
         }
 
-        let url = get_holiday_data_file_url(year, language, country, &source);
-        info!("remote url is {}", &url);
-        let content = download_file(&url).await;
-        if let Ok(content_str) = content {
-            let holiday_of_ylc = parse_holidays(&content_str)?;
-            // save to local file
-            if let Some(old_version) = old_version {
-                if (old_version < holiday_of_ylc.version) {
-                    // save file
-                    let save_res =
-                        save_holidays_file(year, language, country, content_str.as_bytes()).await;
+        let ylc_key = get_ylc_code(year, language, country);
+        let meta = self
+            .meta
+            .get_or_init(|| HolidayManager::load_meta(&self.http_client, &sources))
+            .await;
+        let cache_path = get_holiday_cache_file_path(year, language, country);
+        // 只有缓存文件存在时，条件请求头才有意义
+        let cached = cache
+            .and(cache_path.as_deref())
+            .and_then(load_cache_validators);
+        let remote_state =
+            match plan_remote_sources(meta.as_ref(), &sources, &ylc_key, cache, cached.is_some()) {
+                RemotePlan::Skip => LoadRemoteState::Finish,
+                RemotePlan::Missing => {
+                    info!("{} not exist in remote meta, skip", &ylc_key);
+                    LoadRemoteState::Missing
                 }
-            } else {
-                let save_res =
-                    save_holidays_file(year, language, country, content_str.as_bytes()).await;
+                RemotePlan::Download(download_sources) => {
+                    self.download_remote_file(
+                        year,
+                        language,
+                        country,
+                        download_sources,
+                        cache.map(|(_, version)| version),
+                        cached.as_ref(),
+                    )
+                    .await?
+                }
+            };
+        let failed = matches!(remote_state, LoadRemoteState::Failed);
+        {
+            let mut property = self.property.lock().await;
+            if let Some(ylc_update_state) = property.ylc_holiday_update_state.get_mut(&ylc_key) {
+                ylc_update_state.load_remote_state = remote_state;
             }
+        }
+        if failed {
+            self.tx.send(AppEvent::HolidayLoadFailed(ylc_key))?;
+        }
+        Ok(())
+    }
 
-            tx.send(AppEvent::UpdateHoliday(
-                get_ylc_code(year, language, country),
-                holiday_of_ylc,
-            ))?;
-            {
-                let mut property = property.lock().await;
-                let ylc_update_state = property
-                    .ylc_holiday_update_state
-                    .get_mut(&get_ylc_code(year, language, country))
-                    .unwrap();
-                ylc_update_state.load_remote_state = LoadRemoteState::Finish;
+    /// 按顺序从数据源下载并保存到缓存，返回下载后的状态
+    async fn download_remote_file(
+        &self,
+        year: &str,
+        language: &str,
+        country: &str,
+        sources: Vec<&Source>,
+        old_version: Option<i32>,
+        cached: Option<&(String, CacheValidators)>,
+    ) -> Result<LoadRemoteState> {
+        let content = fetch_holidays_from_sources(
+            &self.http_client,
+            year,
+            language,
+            country,
+            sources,
+            cached,
+            self.meta.get().and_then(Option::as_ref),
+        )
+        .await;
+        match content {
+            Some(FetchHolidays::Downloaded {
+                content,
+                holidays,
+                source,
+                validators,
+            }) => {
+                // 版本相同时也保存，以更新条件请求头和修改时间
+                if old_version.is_none_or(|old_version| old_version <= holidays.version) {
+                    if let Err(err) = save_holidays_file(
+                        year,
+                        language,
+                        country,
+                        source,
+                        &validators,
+                        content.as_bytes(),
+                    )
+                    .await
+                    {
+                        error!("save holiday file fail: {}", err);
+                    }
+                }
+                self.tx.send(AppEvent::UpdateHoliday(
+                    get_ylc_code(year, language, country),
+                    holidays,
+                ))?;
+                Ok(LoadRemoteState::Finish)
             }
+            // 缓存已是最新，只更新修改时间，version 不变
+            Some(FetchHolidays::NotModified) => {
+                if let Some(path) = get_holiday_cache_file_path(year, language, country) {
+                    if let Err(err) = touch_cache_file(&path) {
+                        error!("touch holiday cache file fail: {}", err);
+                    }
+                }
+                Ok(LoadRemoteState::Finish)
+            }
+            // 远程还没有发布这一年的数据，与 meta 中没有该文件一样，不提示下载失败
+            Some(FetchHolidays::NotFound) => Ok(LoadRemoteState::Missing),
+            None => Ok(LoadRemoteState::Failed),
         }
-        return Ok(());
+    }
+
+    /// 重新下载所有失败的 year_lang_country，返回重试的数量
+    pub async fn retry_failed(&self, sources: Vec<Source>) -> usize {
+        let failed: Vec<(String, Option<(NaiveDateTime, i32)>)> = {
+            let mut property = self.property.lock().await;
+            property
+                .ylc_holiday_update_state
+                .iter_mut()
+                .filter(|(_, state)| matches!(state.load_remote_state, LoadRemoteState::Failed))
+                .map(|(key, state)| {
+                    state.load_remote_state = LoadRemoteState::None;
+                    (key.clone(), state.cache)
+                })
+                .collect()
+        };
+        for (ylc_key, cache) in &failed {
+            let Some((year, language, country)) = parse_ylc_code(ylc_key) else {
+                continue;
+            };
+            let manager = self.clone();
+            let (year, language, country) =
+                (year.to_string(), language.to_string(), country.to_string());
+            let sources = sources.clone();
+            let cache = *cache;
+            tokio::spawn(async move {
+                let _ = manager
+                    .load_remote_file(&year, &language, &country, sources, cache)
+                    .await;
+            });
+        }
+        failed.len()
     }
 
     pub async fn load_ylc_holiday(
@@ -216,7 +501,7 @@ impl HolidayManager {
         year: &str,
         language: &str,
         country: &str,
-        source: Source,
+        sources: Vec<Source>,
     ) {
         {
             let mut property = self.property.lock().await;
@@ -225,8 +510,9 @@ impl HolidayManager {
                 .entry(get_ylc_code(year, language, country))
                 .or_insert(YlcHolidayUpdateState {
                     loaded_local_cache: false,
-                    local_cache_time: None,
+                    loaded_embedded: false,
                     load_remote_state: LoadRemoteState::None,
+                    cache: None,
                 });
 
             let load_cache_res = HolidayManager::load_local_cache(
@@ -237,42 +523,47 @@ impl HolidayManager {
                 self.tx.clone(),
             );
 
-            let mut old_version: Option<i32> = None;
-            match load_cache_res {
-                Ok((modify_time, version)) => {
-                    old_version = Some(version);
+            if let Err(err) = HolidayManager::load_embedded(
+                ylc_update_property,
+                year,
+                language,
+                country,
+                load_cache_res.is_ok(),
+                self.tx.clone(),
+            ) {
+                error!("load embedded holiday fail: {}", err);
+            }
+
+            let cache = match load_cache_res {
+                Ok((Some(modify_time), version)) => {
                     info!("load local cache success");
-                    if let Some(modify_time) = modify_time {
-                        if !is_need_update(modify_time) {
-                            return;
-                        }
-                    } else {
-                        return;
-                    }
+                    ylc_update_property.cache = Some((modify_time, version));
+                    Some((modify_time, version))
                 }
+                // 缓存已经加载过，远程也已处理过
+                Ok((None, _)) => return,
                 Err(_) => {
                     info!("load local cache fail");
+                    None
                 }
-            }
-            let property_clone = self.property.clone();
-            let tx_clone = self.tx.clone();
+            };
+            let manager = self.clone();
 
             let year_owned = year.to_string();
             let language_owned = language.to_string();
             let country_owned = country.to_string();
-            let source_owned = source.clone();
+            let sources_owned = sources.clone();
 
             tokio::spawn(async move {
-                let load_remote_res = HolidayManager::load_remote_file(
-                    property_clone,
-                    &year_owned,
-                    &language_owned,
-                    &country_owned,
-                    source_owned,
-                    tx_clone,
-                    old_version,
-                )
-                .await;
+                let _ = manager
+                    .load_remote_file(
+                        &year_owned,
+                        &language_owned,
+                        &country_owned,
+                        sources_owned,
+                        cache,
+                    )
+                    .await;
             });
         }
     }
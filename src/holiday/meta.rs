@@ -0,0 +1,283 @@
+use crate::config::model::Source;
+use crate::config::xdg::Xdg;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::manager::is_need_update;
+
+/// resources/holidays/meta.json，记录远程每个 year_lang_country 文件的版本
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HolidayMeta {
+    pub files: HashMap<String, HolidayMetaFile>,
+    #[serde(default)]
+    pub last_updated: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HolidayMetaFile {
+    pub version: i32,
+    /// 文件内容的 SHA-256，由 riqi-data 生成
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
+
+/// meta.json 及其所在的数据源，meta 只用来判断这个数据源上的文件
+#[derive(Debug)]
+pub struct SourceMeta {
+    pub source: String,
+    pub meta: HolidayMeta,
+}
+
+/// 对某个 year_lang_country 远程文件的处理方式
+#[derive(Debug, PartialEq)]
+pub enum RemoteAction {
+    /// 本地缓存已是最新
+    Skip,
+    /// 远程不存在该文件，不再请求
+    Missing,
+    Download,
+}
+
+pub fn get_meta_cache_path() -> Option<PathBuf> {
+    let mut path = Xdg::cache_dir()?;
+    path.push("holidays");
+    path.push("meta.json");
+    Some(path)
+}
+
+pub fn parse_holiday_meta(json_str: &str) -> Result<HolidayMeta, serde_json::Error> {
+    serde_json::from_str(json_str)
+}
+
+/// 上次成功下载的 meta.json，远程不可用时使用
+pub fn load_cached_meta() -> Option<HolidayMeta> {
+    let content = std::fs::read_to_string(get_meta_cache_path()?).ok()?;
+    parse_holiday_meta(&content).ok()
+}
+
+impl HolidayMeta {
+    pub fn version_of(&self, ylc_key: &str) -> Option<i32> {
+        self.files.get(ylc_key).map(|file| file.version)
+    }
+}
+
+impl SourceMeta {
+    /// 检查从 source 下载的文件内容是否与 meta 中的校验和一致，
+    /// 不是 meta 所在的数据源或 meta 中没有校验和时不检查
+    pub fn verify_checksum(&self, source: &Source, ylc_key: &str, content: &str) -> bool {
+        if self.source != source.as_str() {
+            return true;
+        }
+        match self
+            .meta
+            .files
+            .get(ylc_key)
+            .and_then(|file| file.sha256.as_deref())
+        {
+            Some(sha256) => hex::encode(Sha256::digest(content.as_bytes())) == sha256,
+            None => true,
+        }
+    }
+}
+
+/// 决定是否需要下载远程文件。
+/// 有 meta 时按版本号比较，meta 中没有的 key 视为远程不存在；
+/// 没有 meta 时，缓存有 ETag/Last-Modified 则发送条件请求 (未修改时服务器只返回 304)，
+/// 否则退回到按缓存文件修改时间判断
+pub fn decide_remote_action(
+    meta: Option<&HolidayMeta>,
+    ylc_key: &str,
+    cache: Option<(NaiveDateTime, i32)>,
+    has_validators: bool,
+) -> RemoteAction {
+    match meta {
+        Some(meta) => match (meta.version_of(ylc_key), cache) {
+            (None, _) => RemoteAction::Missing,
+            (Some(remote_version), Some((_, cache_version))) if cache_version >= remote_version => {
+                RemoteAction::Skip
+            }
+            _ => RemoteAction::Download,
+        },
+        None => match cache {
+            Some((modify_time, _)) if !has_validators && !is_need_update(modify_time) => {
+                RemoteAction::Skip
+            }
+            _ => RemoteAction::Download,
+        },
+    }
+}
+
+/// 对所有数据源的处理方式，Download 中是按优先级需要尝试的数据源
+#[derive(Debug, PartialEq)]
+pub enum RemotePlan<'a> {
+    Skip,
+    Missing,
+    Download(Vec<&'a Source>),
+}
+
+/// 按数据源分别决定是否下载：meta 所在的数据源按 meta 判断，
+/// 其他数据源 (例如没有 meta.json 的模板和镜像) 按没有 meta 的规则判断。
+/// meta 中没有该文件时只跳过 meta 所在的数据源，其他数据源仍然会尝试
+pub fn plan_remote_sources<'a>(
+    meta: Option<&SourceMeta>,
+    sources: &'a [Source],
+    ylc_key: &str,
+    cache: Option<(NaiveDateTime, i32)>,
+    has_validators: bool,
+) -> RemotePlan<'a> {
+    let mut plan = RemotePlan::Missing;
+    for source in sources {
+        let source_meta = meta
+            .filter(|meta| meta.source == source.as_str())
+            .map(|meta| &meta.meta);
+        match decide_remote_action(source_meta, ylc_key, cache, has_validators) {
+            // meta 中的版本说明缓存已是最新，其他数据源也不用再请求
+            RemoteAction::Skip if source_meta.is_some() => return RemotePlan::Skip,
+            RemoteAction::Skip => {
+                if plan == RemotePlan::Missing {
+                    plan = RemotePlan::Skip;
+                }
+            }
+            RemoteAction::Missing => {}
+            RemoteAction::Download => match &mut plan {
+                RemotePlan::Download(download) => download.push(source),
+                _ => plan = RemotePlan::Download(vec![source]),
+            },
+        }
+    }
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_decide_remote_action() {
+        let meta = parse_holiday_meta(
+            r#"{"files": {"2026_zh_cn": {"version": 3}}, "last_updated": "2026-01-01"}"#,
+        )
+        .unwrap();
+        let old_time = Utc::now().naive_utc() - Duration::days(30);
+        let new_time = Utc::now().naive_utc();
+
+        assert_eq!(
+            decide_remote_action(Some(&meta), "2026_zh_cn", Some((old_time, 3)), false),
+            RemoteAction::Skip
+        );
+        assert_eq!(
+            decide_remote_action(Some(&meta), "2026_zh_cn", Some((new_time, 2)), true),
+            RemoteAction::Download
+        );
+        assert_eq!(
+            decide_remote_action(Some(&meta), "2026_zh_cn", None, false),
+            RemoteAction::Download
+        );
+        assert_eq!(
+            decide_remote_action(Some(&meta), "2099_zh_cn", None, false),
+            RemoteAction::Missing
+        );
+
+        // 没有 meta 时按缓存修改时间判断
+        assert_eq!(
+            decide_remote_action(None, "2026_zh_cn", Some((new_time, 1)), false),
+            RemoteAction::Skip
+        );
+        assert_eq!(
+            decide_remote_action(None, "2026_zh_cn", Some((old_time, 1)), false),
+            RemoteAction::Download
+        );
+        // 有条件请求头时总是发送条件请求，由服务器判断是否修改
+        assert_eq!(
+            decide_remote_action(None, "2026_zh_cn", Some((new_time, 1)), true),
+            RemoteAction::Download
+        );
+    }
+
+    #[test]
+    fn test_plan_remote_sources() {
+        let github_meta = SourceMeta {
+            source: "github".to_string(),
+            meta: parse_holiday_meta(r#"{"files": {"2026_zh_cn": {"version": 3}}}"#).unwrap(),
+        };
+        let mirror = Source::Custom("https://mirror.example.com/{year}.json".to_string());
+        let sources = vec![mirror.clone(), Source::Github];
+        let old_time = Utc::now().naive_utc() - Duration::days(30);
+        let new_time = Utc::now().naive_utc();
+
+        // github 的 meta 中没有 2030，镜像仍然要尝试
+        assert_eq!(
+            plan_remote_sources(Some(&github_meta), &sources, "2030_zh_cn", None, false),
+            RemotePlan::Download(vec![&mirror])
+        );
+        // meta 说明有新版本时两个数据源都可以下载
+        assert_eq!(
+            plan_remote_sources(
+                Some(&github_meta),
+                &sources,
+                "2026_zh_cn",
+                Some((new_time, 2)),
+                false
+            ),
+            RemotePlan::Download(vec![&Source::Github])
+        );
+        assert_eq!(
+            plan_remote_sources(
+                Some(&github_meta),
+                &sources,
+                "2026_zh_cn",
+                Some((old_time, 2)),
+                false
+            ),
+            RemotePlan::Download(vec![&mirror, &Source::Github])
+        );
+        // meta 说明缓存已是最新
+        assert_eq!(
+            plan_remote_sources(
+                Some(&github_meta),
+                &sources,
+                "2026_zh_cn",
+                Some((old_time, 3)),
+                false
+            ),
+            RemotePlan::Skip
+        );
+        // 只有 meta 所在的数据源，且 meta 中没有该文件
+        assert_eq!(
+            plan_remote_sources(Some(&github_meta), &sources[1..], "2030_zh_cn", None, false),
+            RemotePlan::Missing
+        );
+        // meta 来自另一个数据源时不影响判断
+        let gitee_sources = vec![Source::Gitee];
+        assert_eq!(
+            plan_remote_sources(
+                Some(&github_meta),
+                &gitee_sources,
+                "2030_zh_cn",
+                None,
+                false
+            ),
+            RemotePlan::Download(vec![&Source::Gitee])
+        );
+    }
+
+    #[test]
+    fn test_bundled_meta_lists_bundled_files() {
+        let meta = parse_holiday_meta(include_str!("../../resources/holidays/meta.json")).unwrap();
+        assert!(meta.version_of("2026_zh_cn").is_some());
+
+        let source_meta = SourceMeta {
+            source: "github".to_string(),
+            meta,
+        };
+        let content = include_str!("../../resources/holidays/2026/zh_cn.json");
+        assert!(source_meta.verify_checksum(&Source::Github, "2026_zh_cn", content));
+        assert!(!source_meta.verify_checksum(&Source::Github, "2026_zh_cn", "{}"));
+        // 其他数据源的文件不按这份 meta 检查
+        assert!(source_meta.verify_checksum(&Source::Gitee, "2026_zh_cn", "{}"));
+    }
+}
@@ -1,3 +1,12 @@
+pub mod cache;
+pub mod embedded;
+pub mod http;
+pub mod leave;
 pub mod manager;
-pub mod modal;
+pub mod meta;
+pub use riqi::modal;
+pub mod overrides;
+pub mod rules;
+pub mod source;
 pub mod utils;
+pub mod workdays;
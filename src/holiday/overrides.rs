@@ -0,0 +1,280 @@
+use crate::config::xdg::Xdg;
+use crate::holiday::modal::{Holiday, HolidayOfYearList, PrimaryType};
+use crate::holiday::utils::parse_ylc_code;
+use chrono::{Datelike, NaiveDate};
+use log::error;
+use serde::Deserialize;
+
+/// 用户自定义的节假日调整，位于配置目录下的 holiday_overrides.toml，
+/// 在下载或内置的数据之上合并
+#[derive(Debug, Default, Deserialize)]
+pub struct HolidayOverrides {
+    #[serde(default)]
+    pub add: Vec<AddOverride>,
+    #[serde(default)]
+    pub remove: Vec<RemoveOverride>,
+    #[serde(default)]
+    pub retag: Vec<RetagOverride>,
+}
+
+/// 新增一个节日，例如公司额外的假期
+#[derive(Debug, Deserialize)]
+pub struct AddOverride {
+    pub date: String,
+    pub name: String,
+    /// 内置类型（如 "National holiday"）或自定义类型名称
+    #[serde(rename = "type")]
+    pub holiday_type: String,
+    /// 自定义类型是否放假，默认放假
+    pub rest: Option<bool>,
+    /// 适用的国家，默认为配置中的 country
+    pub country: Option<String>,
+}
+
+/// 删除某天的节日，不指定 name 时删除当天所有节日
+#[derive(Debug, Deserialize)]
+pub struct RemoveOverride {
+    pub date: String,
+    pub name: Option<String>,
+    pub country: Option<String>,
+}
+
+/// 修改某天节日的类型，不指定 name 时修改当天所有节日
+#[derive(Debug, Deserialize)]
+pub struct RetagOverride {
+    pub date: String,
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub holiday_type: String,
+    pub rest: Option<bool>,
+    pub country: Option<String>,
+}
+
+/// 解析类型名称，无法识别的名称作为自定义类型
+fn parse_primary_type(name: &str, rest: Option<bool>) -> PrimaryType {
+    serde_json::from_value(serde_json::Value::String(name.to_string()))
+        .ok()
+        .filter(PrimaryType::is_known)
+        .unwrap_or(PrimaryType::Custom {
+            name: name.to_string(),
+            rest: rest.unwrap_or(true),
+        })
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+/// 日期无效的条目在加载时报告一次并丢弃
+fn retain_valid_dates<T>(entries: &mut Vec<T>, date_of: impl Fn(&T) -> &str) {
+    entries.retain(|entry| {
+        let valid = parse_date(date_of(entry)).is_some();
+        if !valid {
+            error!("invalid date in holiday overrides: {}", date_of(entry));
+        }
+        valid
+    });
+}
+
+/// 判断条目是否作用于这一年的这个国家
+fn is_target(
+    date: &str,
+    country: &Option<String>,
+    default_country: &str,
+    year: i32,
+    target_country: &str,
+) -> Option<NaiveDate> {
+    let date = parse_date(date)?;
+    let country = country.as_deref().unwrap_or(default_country);
+    (date.year() == year && country.eq_ignore_ascii_case(target_country)).then_some(date)
+}
+
+fn matches_holiday(holiday: &Holiday, date: NaiveDate, name: &Option<String>) -> bool {
+    holiday.date.iso == date.format("%Y-%m-%d").to_string()
+        && name.as_ref().is_none_or(|name| &holiday.name == name)
+}
+
+impl HolidayOverrides {
+    /// 去掉日期无效的条目，之后每次合并时不再重复报告
+    fn validate(mut self) -> Self {
+        retain_valid_dates(&mut self.add, |add| &add.date);
+        retain_valid_dates(&mut self.remove, |remove| &remove.date);
+        retain_valid_dates(&mut self.retag, |retag| &retag.date);
+        self
+    }
+
+    /// 把调整合并到某个 year_lang_country 的节假日列表上，
+    /// 依次执行删除、修改类型、新增，version 保持不变
+    pub fn apply(
+        &self,
+        ylc_key: &str,
+        default_country: &str,
+        mut holiday_list: HolidayOfYearList,
+    ) -> HolidayOfYearList {
+        let Some((year, _, country)) = parse_ylc_code(ylc_key) else {
+            return holiday_list;
+        };
+
+        for remove in &self.remove {
+            if let Some(date) = is_target(
+                &remove.date,
+                &remove.country,
+                default_country,
+                year,
+                country,
+            ) {
+                holiday_list
+                    .holidays
+                    .retain(|holiday| !matches_holiday(holiday, date, &remove.name));
+            }
+        }
+
+        for retag in &self.retag {
+            if let Some(date) =
+                is_target(&retag.date, &retag.country, default_country, year, country)
+            {
+                let primary_type = parse_primary_type(&retag.holiday_type, retag.rest);
+                for holiday in holiday_list
+                    .holidays
+                    .iter_mut()
+                    .filter(|holiday| matches_holiday(holiday, date, &retag.name))
+                {
+                    holiday.holiday_type = vec![primary_type.name()];
+                    holiday.primary_type = primary_type.clone();
+                }
+            }
+        }
+
+        for add in &self.add {
+            if let Some(date) = is_target(&add.date, &add.country, default_country, year, country) {
+                holiday_list.holidays.push(Holiday::new(
+                    &add.name,
+                    date,
+                    parse_primary_type(&add.holiday_type, add.rest),
+                    None,
+                ));
+            }
+        }
+
+        holiday_list
+    }
+}
+
+pub fn load_holiday_overrides() -> HolidayOverrides {
+    let Some(path) = Xdg::config_dir().map(|dir| dir.join("holiday_overrides.toml")) else {
+        return HolidayOverrides::default();
+    };
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return HolidayOverrides::default();
+    };
+    toml::from_str(&content)
+        .map(HolidayOverrides::validate)
+        .unwrap_or_else(|err| {
+            error!("parse {} fail: {}", path.display(), err);
+            HolidayOverrides::default()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holiday::utils::{get_holiday_state, DayState};
+
+    fn holiday_list() -> HolidayOfYearList {
+        let date = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        HolidayOfYearList {
+            version: 3,
+            holidays: vec![
+                Holiday::new("国庆节", date(1), PrimaryType::NationalHoliday, None),
+                Holiday::new("国庆假", date(7), PrimaryType::NationalHoliday, None),
+                Holiday::new("调休", date(10), PrimaryType::WorkingDayOnWeekend, None),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let overrides: HolidayOverrides = toml::from_str(
+            r#"
+            [[add]]
+            date = "2026-10-09"
+            name = "Company day"
+            type = "Company holiday"
+
+            [[add]]
+            date = "2026-10-09"
+            name = "Other country"
+            type = "National holiday"
+            country = "de"
+
+            [[remove]]
+            date = "2026-10-10"
+
+            [[retag]]
+            date = "2026-10-07"
+            name = "国庆假"
+            type = "On call"
+            rest = false
+            "#,
+        )
+        .unwrap();
+        let list = overrides.apply("2026_zh_cn", "cn", holiday_list());
+        assert_eq!(list.version, 3);
+        let names: Vec<&str> = list.holidays.iter().map(|h| h.name.as_str()).collect();
+        assert_eq!(names, vec!["国庆节", "国庆假", "Company day"]);
+
+        let company_day = Some(vec![list.holidays[2].clone()]);
+        // 2026-10-09 是星期五
+        assert_eq!(
+            get_holiday_state(&company_day, 5, None),
+            (DayState::Rest, true)
+        );
+        let on_call = Some(vec![list.holidays[1].clone()]);
+        assert_eq!(get_holiday_state(&on_call, 3, None), (DayState::Work, true));
+        assert_eq!(list.holidays[1].holiday_type, vec!["On call"]);
+
+        // 其他年份和国家不受影响
+        let list = overrides.apply("2025_zh_cn", "cn", holiday_list());
+        assert_eq!(list.holidays.len(), 3);
+    }
+
+    #[test]
+    fn test_apply_overrides_without_data() {
+        let overrides = toml::from_str::<HolidayOverrides>(
+            r#"
+            [[add]]
+            date = "2031-05-02"
+            name = "Company day"
+            type = "Company holiday"
+
+            [[add]]
+            date = "2031-13-01"
+            name = "Typo"
+            type = "Company holiday"
+            "#,
+        )
+        .unwrap()
+        .validate();
+        assert_eq!(overrides.add.len(), 1);
+        let empty = HolidayOfYearList {
+            version: 0,
+            holidays: vec![],
+        };
+        let list = overrides.apply("2031_de_de", "de", empty);
+        assert_eq!(list.version, 0);
+        assert_eq!(list.holidays.len(), 1);
+        assert_eq!(list.holidays[0].date.iso, "2031-05-02");
+    }
+
+    #[test]
+    fn test_parse_primary_type() {
+        assert!(matches!(
+            parse_primary_type("Substitute holiday", None),
+            PrimaryType::SubstituteHoliday
+        ));
+        assert!(matches!(
+            parse_primary_type("Team offsite", Some(false)),
+            PrimaryType::Custom { rest: false, .. }
+        ));
+    }
+}
@@ -0,0 +1,403 @@
+use crate::holiday::modal::{Holiday, HolidayOfYearList, PrimaryType};
+use crate::holiday::utils::get_lc_code;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use include_dir::{include_dir, Dir};
+use serde::Deserialize;
+use std::collections::HashSet;
+use tyme4rs::tyme::lunar::{LunarDay, LunarMonth};
+use tyme4rs::tyme::solar::{SolarDay, SolarTerm, SOLAR_TERM_NAMES};
+
+/// 节假日规则文件，按 `{language}_{country}.json` 命名，在没有节假日数据文件的年份使用
+static HOLIDAY_RULES: Dir = include_dir!("$CARGO_MANIFEST_DIR/resources/holiday_rules");
+
+#[derive(Debug, Deserialize)]
+pub struct HolidayRuleFile {
+    pub holidays: Vec<HolidayRule>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HolidayRule {
+    pub name: String,
+    pub primary_type: PrimaryType,
+    pub rule: RuleKind,
+    /// 连续放假的天数
+    #[serde(default = "default_days")]
+    pub days: u32,
+    /// 落在周末时的补休规则
+    #[serde(default)]
+    pub substitute: Option<Substitute>,
+    /// 地区性节日适用的行政区
+    #[serde(default)]
+    pub subdivisions: Option<Vec<String>>,
+}
+
+fn default_days() -> u32 {
+    1
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RuleKind {
+    /// 固定日期，例如 1 月 1 日
+    Fixed { month: u32, day: u32 },
+    /// 某月的第 n 个星期几，n 为负数时从月末倒数，-1 表示最后一个
+    NthWeekday {
+        month: u32,
+        weekday: Weekday,
+        n: i32,
+    },
+    /// 相对复活节的偏移天数，orthodox 为 true 时使用东正教复活节
+    Easter {
+        offset: i64,
+        #[serde(default)]
+        orthodox: bool,
+    },
+    /// 农历日期，day 为 -1 表示该月最后一天（如除夕）
+    Lunar { month: i32, day: i32 },
+    /// 二十四节气，例如 "清明"
+    SolarTerm { name: String },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Substitute {
+    /// 落在周末时顺延到下一个工作日
+    NextWeekday,
+    /// 周六提前到周五，周日顺延到周一（美国联邦假日的规则）
+    NearestWeekday,
+}
+
+impl Substitute {
+    /// 周末节日的补休日，已被其他节日占用时继续往后顺延
+    fn observed_date(&self, date: NaiveDate, taken: &HashSet<NaiveDate>) -> NaiveDate {
+        let mut observed = match (self, date.weekday()) {
+            (Substitute::NearestWeekday, Weekday::Sat) => date - Duration::days(1),
+            _ => date + Duration::days(1),
+        };
+        while is_weekend(observed) || taken.contains(&observed) {
+            observed += Duration::days(1);
+        }
+        observed
+    }
+}
+
+fn solar_day_to_naive(solar_day: SolarDay) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(
+        solar_day.get_year() as i32,
+        solar_day.get_month() as u32,
+        solar_day.get_day() as u32,
+    )
+}
+
+/// 公历复活节（匿名格里高利算法）
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// 东正教复活节（Meeus 儒略历算法），换算为公历
+pub fn orthodox_easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 4;
+    let b = year % 7;
+    let c = year % 19;
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let month = (d + e + 114) / 31;
+    let day = (d + e + 114) % 31 + 1;
+    // 儒略历与公历的差值：1900-2099 年为 13 天
+    let gap = year / 100 - year / 400 - 2;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+        .map(|date| date + Duration::days(gap as i64))
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: i32) -> Option<NaiveDate> {
+    if n > 0 {
+        return NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8);
+    }
+    if n == 0 {
+        return None;
+    }
+    // 从月末往前找
+    let first_of_next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    let last_day = first_of_next.pred_opt()?;
+    let back = (7 + last_day.weekday().num_days_from_monday() as i64
+        - weekday.num_days_from_monday() as i64)
+        % 7;
+    let date = last_day - Duration::days(back + 7 * (-n as i64 - 1));
+    (date.month() == month).then_some(date)
+}
+
+impl RuleKind {
+    /// 计算规则在公历某年内的开始日期。
+    /// 农历与节气规则可能跨越公历年，所以会尝试相邻年份后再按公历年筛选
+    pub fn start_dates(&self, year: i32) -> Vec<NaiveDate> {
+        match self {
+            RuleKind::Fixed { month, day } => NaiveDate::from_ymd_opt(year, *month, *day)
+                .into_iter()
+                .collect(),
+            RuleKind::NthWeekday { month, weekday, n } => nth_weekday(year, *month, *weekday, *n)
+                .into_iter()
+                .collect(),
+            RuleKind::Easter { offset, orthodox } => {
+                let easter = if *orthodox {
+                    orthodox_easter_sunday(year)
+                } else {
+                    easter_sunday(year)
+                };
+                easter
+                    .map(|date| date + Duration::days(*offset))
+                    .into_iter()
+                    .collect()
+            }
+            RuleKind::Lunar { month, day } => {
+                if !(1..=12).contains(month) {
+                    return vec![];
+                }
+                (year - 1..=year)
+                    .filter_map(|lunar_year| {
+                        let day = if *day < 0 {
+                            LunarMonth::from_ym(lunar_year as isize, *month as isize)
+                                .get_day_count() as i32
+                                + day
+                                + 1
+                        } else {
+                            *day
+                        };
+                        let lunar_day =
+                            LunarDay::new(lunar_year as isize, *month as isize, day as usize)
+                                .ok()?;
+                        solar_day_to_naive(lunar_day.get_solar_day())
+                    })
+                    .filter(|date| date.year() == year)
+                    .collect()
+            }
+            RuleKind::SolarTerm { name } => {
+                if !SOLAR_TERM_NAMES.contains(&name.as_str()) {
+                    return vec![];
+                }
+                (year..=year + 1)
+                    .filter_map(|term_year| {
+                        let term = SolarTerm::from_name(term_year as isize, name);
+                        solar_day_to_naive(term.get_julian_day().get_solar_day())
+                    })
+                    .filter(|date| date.year() == year)
+                    .collect()
+            }
+        }
+    }
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+impl HolidayRuleFile {
+    /// 按规则生成某一年的节假日，生成的数据 version 为 0，
+    /// 这样任何真实的数据文件都会优先于规则生成的数据
+    pub fn generate(&self, year: i32) -> HolidayOfYearList {
+        let mut holidays = vec![];
+        let mut taken: HashSet<NaiveDate> = HashSet::new();
+        let mut weekend_days: Vec<(&HolidayRule, &Substitute, NaiveDate)> = vec![];
+
+        for rule in &self.holidays {
+            // 补休可能跨年，例如周六的元旦提前到上一年的 12 月 31 日，
+            // 所以前后两年的节日也要参与补休计算
+            for rule_year in year - 1..=year + 1 {
+                for start in rule.rule.start_dates(rule_year) {
+                    for offset in 0..rule.days {
+                        let date = start + Duration::days(offset as i64);
+                        if date.year() != rule_year {
+                            continue;
+                        }
+                        if rule_year == year {
+                            holidays.push(Holiday::new(
+                                &rule.name,
+                                date,
+                                rule.primary_type.clone(),
+                                rule.subdivisions.clone(),
+                            ));
+                        }
+                        taken.insert(date);
+                        if let Some(substitute) = &rule.substitute {
+                            if is_weekend(date) {
+                                weekend_days.push((rule, substitute, date));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // 补休放在所有节日确定之后，避免补休日和其他节日重叠
+        for (rule, substitute, date) in weekend_days {
+            let observed = substitute.observed_date(date, &taken);
+            taken.insert(observed);
+            if observed.year() != year {
+                continue;
+            }
+            holidays.push(Holiday::new(
+                &rule.name,
+                observed,
+                PrimaryType::SubstituteHoliday,
+                rule.subdivisions.clone(),
+            ));
+        }
+
+        holidays.sort_by(|a, b| a.date.iso.cmp(&b.date.iso));
+        HolidayOfYearList {
+            version: 0,
+            holidays,
+        }
+    }
+}
+
+pub fn load_holiday_rules(language: &str, country: &str) -> Option<HolidayRuleFile> {
+    let path = format!("{}.json", get_lc_code(language, country));
+    let content = HOLIDAY_RULES.get_file(&path)?.contents_utf8()?;
+    serde_json::from_str(content).ok()
+}
+
+/// 根据内置规则生成节假日，没有对应规则文件时返回 None
+pub fn generate_holidays_from_rules(
+    year: &str,
+    language: &str,
+    country: &str,
+) -> Option<HolidayOfYearList> {
+    let year = year.parse::<i32>().ok()?;
+    load_holiday_rules(language, country).map(|rules| rules.generate(year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn dates_of(list: &HolidayOfYearList, name: &str) -> Vec<String> {
+        list.holidays
+            .iter()
+            .filter(|holiday| holiday.name == name)
+            .map(|holiday| holiday.date.iso.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_easter() {
+        assert_eq!(easter_sunday(2024), Some(ymd(2024, 3, 31)));
+        assert_eq!(easter_sunday(2030), Some(ymd(2030, 4, 21)));
+        assert_eq!(orthodox_easter_sunday(2024), Some(ymd(2024, 5, 5)));
+        assert_eq!(orthodox_easter_sunday(2030), Some(ymd(2030, 4, 28)));
+    }
+
+    #[test]
+    fn test_nth_weekday() {
+        // 2030 年感恩节为 11 月第 4 个星期四
+        assert_eq!(
+            nth_weekday(2030, 11, Weekday::Thu, 4),
+            Some(ymd(2030, 11, 28))
+        );
+        // 2030 年阵亡将士纪念日为 5 月最后一个星期一
+        assert_eq!(
+            nth_weekday(2030, 5, Weekday::Mon, -1),
+            Some(ymd(2030, 5, 27))
+        );
+        assert_eq!(
+            nth_weekday(2030, 12, Weekday::Tue, -1),
+            Some(ymd(2030, 12, 31))
+        );
+    }
+
+    #[test]
+    fn test_lunar_and_solar_term_rules() {
+        let spring = RuleKind::Lunar { month: 1, day: 1 };
+        assert_eq!(spring.start_dates(2030), vec![ymd(2030, 2, 3)]);
+        // 2030 年的除夕属于农历 2029 年
+        let new_years_eve = RuleKind::Lunar { month: 12, day: -1 };
+        assert_eq!(new_years_eve.start_dates(2030), vec![ymd(2030, 2, 2)]);
+        let qing_ming = RuleKind::SolarTerm {
+            name: "清明".to_string(),
+        };
+        assert_eq!(qing_ming.start_dates(2030), vec![ymd(2030, 4, 5)]);
+    }
+
+    #[test]
+    fn test_substitute_next_weekday() {
+        // 2028-01-01 是星期六，2028-12-25 是星期一
+        let rules = load_holiday_rules("en", "gb").unwrap();
+        let holidays = rules.generate(2028);
+        assert_eq!(
+            dates_of(&holidays, "New Year's Day"),
+            vec!["2028-01-01", "2028-01-03"]
+        );
+        // 2027 年圣诞节和节礼日都在周末，分别顺延到 27、28 日
+        let holidays = rules.generate(2027);
+        assert_eq!(
+            dates_of(&holidays, "Christmas Day"),
+            vec!["2027-12-25", "2027-12-27"]
+        );
+        assert_eq!(
+            dates_of(&holidays, "Boxing Day"),
+            vec!["2027-12-26", "2027-12-28"]
+        );
+    }
+
+    #[test]
+    fn test_substitute_nearest_weekday() {
+        let rules = load_holiday_rules("en", "us").unwrap();
+        // 2033-01-01 是星期六，补休是 2032-12-31 星期五
+        let holidays = rules.generate(2033);
+        assert_eq!(dates_of(&holidays, "New Year's Day"), vec!["2033-01-01"]);
+        let holidays = rules.generate(2032);
+        assert_eq!(
+            dates_of(&holidays, "New Year's Day"),
+            vec!["2032-01-01", "2032-12-31"]
+        );
+        // 2032-12-25 是星期六，补休是 24 日；2033-12-25 是星期日，补休是 26 日
+        assert_eq!(
+            dates_of(&holidays, "Christmas Day"),
+            vec!["2032-12-24", "2032-12-25"]
+        );
+        let holidays = rules.generate(2033);
+        assert_eq!(
+            dates_of(&holidays, "Christmas Day"),
+            vec!["2033-12-25", "2033-12-26"]
+        );
+    }
+
+    #[test]
+    fn test_embedded_rule_files() {
+        for entry in HOLIDAY_RULES.files() {
+            let content = entry.contents_utf8().unwrap();
+            let rules: HolidayRuleFile = serde_json::from_str(content)
+                .unwrap_or_else(|err| panic!("{}: {}", entry.path().display(), err));
+            assert!(!rules.generate(2030).holidays.is_empty());
+        }
+        let holidays = generate_holidays_from_rules("2030", "zh", "cn").unwrap();
+        assert_eq!(holidays.version, 0);
+        assert!(holidays.is_generated());
+        assert_eq!(dates_of(&holidays, "春节").len(), 3);
+        assert_eq!(
+            dates_of(&holidays, "劳动节"),
+            vec!["2030-05-01", "2030-05-02"]
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::config::model::Source;
+use crate::holiday::cache::CacheValidators;
+use crate::holiday::http::{HttpClient, HttpResponse};
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+use url::Url;
+
+/// 数据源目录下每个节假日文件的相对路径
+const FILE_TEMPLATE: &str = "{year}/{language}_{country}.json";
+
+/// 数据源的文件 URL 模板
+fn get_source_template(source: &Source) -> String {
+    match source {
+        Source::Github => format!(
+            "https://raw.githubusercontent.com/alinGmail/riqi/refs/heads/main/resources/holidays/{}",
+            FILE_TEMPLATE
+        ),
+        Source::Gitee => format!(
+            "https://gitee.com/zhaixiaolin/riqi/raw/main/resources/holidays/{}",
+            FILE_TEMPLATE
+        ),
+        // 没有占位符的视为目录
+        Source::Custom(template) if !template.contains('{') => {
+            format!("{}/{}", template.trim_end_matches('/'), FILE_TEMPLATE)
+        }
+        Source::Custom(template) => template.clone(),
+    }
+}
+
+pub fn get_holiday_data_file_url(
+    year: &str,
+    language: &str,
+    country: &str,
+    source: &Source,
+) -> String {
+    get_source_template(source)
+        .replace("{year}", year)
+        .replace("{language}", language)
+        .replace("{country}", country)
+}
+
+/// meta.json 与年份目录同级，自定义路径结构的模板没有 meta.json
+pub fn get_holiday_meta_file_url(source: &Source) -> Option<String> {
+    get_source_template(source)
+        .strip_suffix(FILE_TEMPLATE)
+        .map(|base| format!("{}meta.json", base))
+}
+
+/// 读取数据源中的文件，file:// 直接读取本地文件，文件不存在时与 HTTP 404 一样返回 NotFound；
+/// 其他通过 HTTP 条件请求下载
+pub async fn fetch_source_file(
+    client: &HttpClient,
+    url: &str,
+    validators: Option<&CacheValidators>,
+) -> Result<HttpResponse> {
+    if !url.starts_with("file://") {
+        return client.get_conditional(url, validators).await;
+    }
+    // 交给 Url 转换，Windows 的 file:///C:/x 才能得到 C:\x
+    let path = Url::parse(url)
+        .ok()
+        .and_then(|file_url| file_url.to_file_path().ok())
+        .ok_or_else(|| eyre!("invalid file url: {}", url))?;
+    match tokio::fs::read_to_string(path).await {
+        Ok(body) => Ok(HttpResponse::Ok {
+            body,
+            validators: CacheValidators::default(),
+        }),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(HttpResponse::NotFound),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_urls() {
+        assert_eq!(
+            get_holiday_data_file_url("2026", "zh", "cn", &Source::Gitee),
+            "https://gitee.com/zhaixiaolin/riqi/raw/main/resources/holidays/2026/zh_cn.json"
+        );
+
+        let mirror = Source::Custom("https://mirror.example.com/holidays/".to_string());
+        assert_eq!(
+            get_holiday_data_file_url("2026", "en", "us", &mirror),
+            "https://mirror.example.com/holidays/2026/en_us.json"
+        );
+        assert_eq!(
+            get_holiday_meta_file_url(&mirror).unwrap(),
+            "https://mirror.example.com/holidays/meta.json"
+        );
+
+        let template = Source::Custom("file:///srv/share/{country}-{year}.json".to_string());
+        assert_eq!(
+            get_holiday_data_file_url("2026", "de", "de", &template),
+            "file:///srv/share/de-2026.json"
+        );
+        assert!(get_holiday_meta_file_url(&template).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_local_source_file() {
+        let dir = Source::Custom(format!(
+            "file://{}/resources/holidays",
+            env!("CARGO_MANIFEST_DIR")
+        ));
+        let client = HttpClient::new(std::time::Duration::from_secs(1), 0, None).unwrap();
+        let response = fetch_source_file(
+            &client,
+            &get_holiday_data_file_url("2026", "zh", "cn", &dir),
+            None,
+        )
+        .await
+        .unwrap();
+        let HttpResponse::Ok { body, validators } = response else {
+            panic!("file source never returns 304");
+        };
+        assert!(body.contains("\"version\""));
+        assert!(validators.is_empty());
+        let meta_url = get_holiday_meta_file_url(&dir).unwrap();
+        assert!(fetch_source_file(&client, &meta_url, None).await.is_ok());
+        let missing = get_holiday_data_file_url("1999", "zh", "cn", &dir);
+        assert_eq!(
+            fetch_source_file(&client, &missing, None).await.unwrap(),
+            HttpResponse::NotFound
+        );
+    }
+}
@@ -1,42 +1,200 @@
 use crate::config::xdg::Xdg;
 use crate::holiday::modal::{Holiday, PrimaryType};
+use serde::Serialize;
 use std::path::PathBuf;
 
 pub fn get_lc_code(language: &str, country: &str) -> String {
-    return format!("{}_{}", language, country);
+    format!("{}_{}", language, country)
 }
 
 pub fn get_ylc_code(year: &str, language: &str, country: &str) -> String {
-    return format!("{}_{}_{}", year, language, country);
+    format!("{}_{}_{}", year, language, country)
+}
+
+/// 把 "2026_zh_cn" 拆分为 (year, language, country)
+pub fn parse_ylc_code(ylc_code: &str) -> Option<(i32, &str, &str)> {
+    let mut parts = ylc_code.splitn(3, '_');
+    let year = parts.next()?.parse::<i32>().ok()?;
+    Some((year, parts.next()?, parts.next()?))
+}
+
+/// 节假日缓存目录，如 ~/.cache/riqi/holidays
+pub fn get_holidays_cache_dir() -> Option<PathBuf> {
+    Some(Xdg::cache_dir()?.join("holidays"))
 }
 
 pub fn get_holiday_cache_file_path(year: &str, language: &str, country: &str) -> Option<PathBuf> {
-    let mut path = Xdg::cache_dir()?;
-    path.push("holidays");
+    let mut path = get_holidays_cache_dir()?;
     path.push(year);
     path.push(format!("{}.json", get_lc_code(language, country)));
     Some(path)
 }
 
+/// 一天的上班/放假状态
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayState {
+    Work,
+    Rest,
+    /// 半天假，如平安夜下午放假
+    HalfDay,
+}
+
+impl DayState {
+    pub fn is_rest(self) -> bool {
+        self == DayState::Rest
+    }
+}
+
 // 判断今天是否是节日，
-// return (是否放假, true 放假，false 上班:bool  | 是否国家节日,用于是否显示图标:bool)
-pub fn get_holiday_state(holidays: &Option<Vec<Holiday>>, day_of_week: u16) -> (bool, bool) {
+// region 为所在的行政区，只有属于该行政区的地区性节日才算放假
+// return (上班/放假/半天假:DayState | 是否国家节日,用于是否显示图标:bool)
+pub fn get_holiday_state(
+    holidays: &Option<Vec<Holiday>>,
+    day_of_week: u16,
+    region: Option<&str>,
+) -> (DayState, bool) {
     if let Some(holiday_vec) = holidays {
-        let is_holiday = holiday_vec.iter().any(|holiday| {
+        let is_holiday = holiday_vec
+            .iter()
+            .any(|holiday| is_rest_holiday(holiday, region));
+        if is_holiday {
+            return (DayState::Rest, true);
+        }
+        let is_workday = holiday_vec.iter().any(|holiday| {
             matches!(
                 holiday.primary_type,
-                PrimaryType::SubstituteHoliday | PrimaryType::NationalHoliday
+                PrimaryType::WorkingDayOnWeekend | PrimaryType::Custom { rest: false, .. }
             )
         });
-        if is_holiday {
-            return (true, true);
-        }
-        let is_workday = holiday_vec
-            .iter()
-            .any(|holiday| matches!(holiday.primary_type, PrimaryType::WorkingDayOnWeekend));
         if is_workday {
-            return (false, true);
+            return (DayState::Work, true);
         }
     };
-    (day_of_week == 6 || day_of_week == 0, false)
+    if day_of_week == 6 || day_of_week == 0 {
+        return (DayState::Rest, false);
+    }
+    if get_half_day_holiday(holidays, region).is_some() {
+        return (DayState::HalfDay, true);
+    }
+    (DayState::Work, false)
+}
+
+/// 该节日在所在行政区是否放假
+pub fn is_rest_holiday(holiday: &Holiday, region: Option<&str>) -> bool {
+    match holiday.primary_type {
+        PrimaryType::SubstituteHoliday | PrimaryType::NationalHoliday => true,
+        PrimaryType::RegionalHoliday => holiday.is_in_region(region),
+        PrimaryType::Custom { rest, .. } => rest,
+        _ => false,
+    }
+}
+
+/// 当天适用于该行政区的半天假
+pub fn get_half_day_holiday<'a>(
+    holidays: &'a Option<Vec<Holiday>>,
+    region: Option<&str>,
+) -> Option<&'a Holiday> {
+    holidays.iter().flatten().find(|holiday| {
+        matches!(holiday.primary_type, PrimaryType::HalfDayHoliday)
+            && (holiday.subdivisions.is_none() || holiday.is_in_region(region))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holiday::modal::{Date, DateTime};
+
+    fn holiday(primary_type: PrimaryType, subdivisions: Option<Vec<&str>>) -> Holiday {
+        Holiday {
+            name: "Fronleichnam".to_string(),
+            date: Date {
+                iso: "2026-06-04".to_string(),
+                datetime: DateTime {
+                    year: 2026,
+                    month: 6,
+                    day: 4,
+                    hour: None,
+                    minute: None,
+                    second: None,
+                },
+            },
+            holiday_type: vec![],
+            primary_type,
+            subdivisions: subdivisions.map(|s| s.iter().map(|s| s.to_string()).collect()),
+        }
+    }
+
+    #[test]
+    fn test_regional_holiday_state() {
+        let holidays = Some(vec![holiday(
+            PrimaryType::RegionalHoliday,
+            Some(vec!["DE-BW", "DE-BY"]),
+        )]);
+        // 2026-06-04 是星期四
+        assert_eq!(
+            get_holiday_state(&holidays, 4, Some("de-BY")),
+            (DayState::Rest, true)
+        );
+        assert_eq!(
+            get_holiday_state(&holidays, 4, Some("DE-BE")),
+            (DayState::Work, false)
+        );
+        assert_eq!(
+            get_holiday_state(&holidays, 4, None),
+            (DayState::Work, false)
+        );
+    }
+
+    #[test]
+    fn test_regional_holiday_visibility() {
+        let regional = holiday(PrimaryType::RegionalHoliday, Some(vec!["DE-BY"]));
+        assert!(regional.is_visible_in_region(None));
+        assert!(regional.is_visible_in_region(Some("DE-BY")));
+        assert!(!regional.is_visible_in_region(Some("DE-BE")));
+        let national = holiday(PrimaryType::NationalHoliday, None);
+        assert!(national.is_visible_in_region(Some("DE-BE")));
+    }
+
+    #[test]
+    fn test_half_day_holiday_state() {
+        let mut half_day = holiday(PrimaryType::HalfDayHoliday, None);
+        half_day.date.datetime.hour = Some(12);
+        assert_eq!(
+            half_day.start_time(),
+            chrono::NaiveTime::from_hms_opt(12, 0, 0)
+        );
+        let holidays = Some(vec![half_day]);
+        assert_eq!(
+            get_holiday_state(&holidays, 4, None),
+            (DayState::HalfDay, true)
+        );
+        // 周末本来就放假
+        assert_eq!(
+            get_holiday_state(&holidays, 6, None),
+            (DayState::Rest, false)
+        );
+    }
+
+    #[test]
+    fn test_unknown_primary_type() {
+        let list = crate::holiday::modal::parse_holidays_of_year(
+            r#"{"version": 1, "holidays": [{"name": "Brückentag",
+                "date": {"iso": "2026-05-15", "datetime": {"year": 2026, "month": 5, "day": 15}},
+                "type": ["Bridge day"], "primary_type": "Bridge day"}]}"#,
+        )
+        .unwrap();
+        let primary_type = &list.holidays[0].primary_type;
+        assert!(matches!(primary_type, PrimaryType::Unknown(name) if name == "Bridge day"));
+        assert!(!primary_type.is_known());
+        assert_eq!(
+            serde_json::to_value(primary_type).unwrap(),
+            serde_json::json!("Bridge day")
+        );
+        assert_eq!(
+            get_holiday_state(&Some(list.holidays), 5, None),
+            (DayState::Work, false)
+        );
+    }
 }
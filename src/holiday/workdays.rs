@@ -0,0 +1,214 @@
+use crate::holiday::modal::Holiday;
+use crate::holiday::utils::{get_holiday_state, is_rest_holiday, DayState};
+use chrono::{Datelike, Days, NaiveDate};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// 向前或向后查找工作日的最大天数，避免数据异常 (整年放假) 时死循环
+const MAX_SEARCH_DAYS: u64 = 366 * 10;
+
+/// 某一年按日期 (yyyy-mm-dd) 分组的节假日
+type DateHolidays = HashMap<String, Vec<Holiday>>;
+
+type YearLoader<'a> = Box<dyn Fn(i32) -> Option<DateHolidays> + 'a>;
+
+/// 下一个放假的节日
+#[derive(Debug, Clone, PartialEq)]
+pub struct NextHoliday {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+/// 按节假日数据计算工作日，与 get_holiday_state 的判断一致：
+/// 调休上班 (WorkingDayOnWeekend) 算工作日，半天假也算工作日
+pub struct WorkdayCalendar<'a> {
+    region: Option<String>,
+    loader: YearLoader<'a>,
+    /// 按年份缓存 loader 的结果，日期为 key
+    years: RefCell<HashMap<i32, Option<DateHolidays>>>,
+}
+
+impl<'a> WorkdayCalendar<'a> {
+    /// loader 返回某一年按日期分组的节假日，没有数据时按普通周末计算
+    pub fn new(region: Option<String>, loader: impl Fn(i32) -> Option<DateHolidays> + 'a) -> Self {
+        WorkdayCalendar {
+            region,
+            loader: Box::new(loader),
+            years: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn holidays_of(&self, date: NaiveDate) -> Option<Vec<Holiday>> {
+        let mut years = self.years.borrow_mut();
+        let holiday_map = years
+            .entry(date.year())
+            .or_insert_with(|| (self.loader)(date.year()));
+        holiday_map
+            .as_ref()
+            .and_then(|map| map.get(&date.format("%Y-%m-%d").to_string()))
+            .cloned()
+    }
+
+    /// 当天放假的节日，周末和调休不算
+    fn rest_holiday_of(&self, date: NaiveDate) -> Option<Holiday> {
+        self.holidays_of(date)?
+            .into_iter()
+            .find(|holiday| is_rest_holiday(holiday, self.region.as_deref()))
+    }
+
+    pub fn day_state(&self, date: NaiveDate) -> DayState {
+        let holidays = self.holidays_of(date);
+        get_holiday_state(
+            &holidays,
+            date.weekday().num_days_from_sunday() as u16,
+            self.region.as_deref(),
+        )
+        .0
+    }
+
+    /// 这一年是否有节假日数据，没有时只按周末计算
+    pub fn has_data(&self, year: i32) -> bool {
+        self.years
+            .borrow_mut()
+            .entry(year)
+            .or_insert_with(|| (self.loader)(year))
+            .is_some()
+    }
+
+    /// 已经查询过但 loader 没有返回数据的年份，这些年份只按周末计算
+    pub fn years_without_data(&self) -> Vec<i32> {
+        let mut years: Vec<i32> = self
+            .years
+            .borrow()
+            .iter()
+            .filter(|(_, holidays)| holidays.is_none())
+            .map(|(year, _)| *year)
+            .collect();
+        years.sort();
+        years
+    }
+
+    pub fn is_workday(&self, date: NaiveDate) -> bool {
+        !self.day_state(date).is_rest()
+    }
+
+    /// 两个日期之间 (包含两端) 的工作日数量，与日期的先后顺序无关
+    pub fn workdays_between(&self, from: NaiveDate, to: NaiveDate) -> u64 {
+        let (from, to) = if from <= to { (from, to) } else { (to, from) };
+        from.iter_days()
+            .take_while(|date| *date <= to)
+            .filter(|date| self.is_workday(*date))
+            .count() as u64
+    }
+
+    /// date 之后 (n 为负数时之前) 的第 n 个工作日，date 本身不计入；n 为 0 时返回 date
+    pub fn add_workdays(&self, date: NaiveDate, n: i64) -> Option<NaiveDate> {
+        let mut current = date;
+        for _ in 0..n.unsigned_abs() {
+            current = if n > 0 {
+                self.next_workday(current)?
+            } else {
+                self.prev_workday(current)?
+            };
+        }
+        Some(current)
+    }
+
+    /// date 之后的第一个工作日
+    pub fn next_workday(&self, date: NaiveDate) -> Option<NaiveDate> {
+        (1..=MAX_SEARCH_DAYS)
+            .filter_map(|days| date.checked_add_days(Days::new(days)))
+            .find(|date| self.is_workday(*date))
+    }
+
+    /// date 之后一年内第一个开始放假的节日。连续的假期 (如国庆节 + 国庆假) 只算第一天，
+    /// 所以正在放假时返回的是下一个假期
+    pub fn next_holiday(&self, date: NaiveDate) -> Option<NextHoliday> {
+        let mut previous_is_holiday = self.rest_holiday_of(date).is_some();
+        for days in 1..=366 {
+            let current = date.checked_add_days(Days::new(days))?;
+            let holiday = self.rest_holiday_of(current);
+            if let Some(holiday) = &holiday {
+                if !previous_is_holiday {
+                    return Some(NextHoliday {
+                        date: current,
+                        name: holiday.name.clone(),
+                    });
+                }
+            }
+            previous_is_holiday = holiday.is_some();
+        }
+        None
+    }
+
+    /// date 之前的最后一个工作日
+    pub fn prev_workday(&self, date: NaiveDate) -> Option<NaiveDate> {
+        (1..=MAX_SEARCH_DAYS)
+            .filter_map(|days| date.checked_sub_days(Days::new(days)))
+            .find(|date| self.is_workday(*date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holiday::embedded::load_embedded_holidays;
+    use crate::holiday::rules::generate_holidays_from_rules;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    fn zh_cn_calendar() -> WorkdayCalendar<'static> {
+        WorkdayCalendar::new(None, |year| {
+            let year = year.to_string();
+            load_embedded_holidays(&year, "zh", "cn")
+                .or_else(|| generate_holidays_from_rules(&year, "zh", "cn"))
+                .map(|list| list.to_holiday_map())
+        })
+    }
+
+    #[test]
+    fn test_workdays_with_make_up_workdays() {
+        let calendar = zh_cn_calendar();
+        // 2026 国庆节 10-01 ~ 10-07 放假，9-20 (周日) 和 10-10 (周六) 调休上班
+        assert!(!calendar.is_workday(date(10, 1)));
+        assert!(calendar.is_workday(date(10, 10)));
+        assert!(calendar.is_workday(date(9, 20)));
+        // 9-28 ~ 10-11：9-28、9-29、9-30、10-08、10-09、10-10
+        assert_eq!(calendar.workdays_between(date(9, 28), date(10, 11)), 6);
+        assert_eq!(calendar.workdays_between(date(10, 11), date(9, 28)), 6);
+        assert_eq!(calendar.next_workday(date(9, 30)), Some(date(10, 8)));
+        assert_eq!(calendar.add_workdays(date(9, 30), 3), Some(date(10, 10)));
+        assert_eq!(calendar.add_workdays(date(10, 8), -1), Some(date(9, 30)));
+        assert_eq!(calendar.add_workdays(date(10, 3), 0), Some(date(10, 3)));
+    }
+
+    #[test]
+    fn test_next_holiday() {
+        let calendar = zh_cn_calendar();
+        let next = calendar.next_holiday(date(9, 1)).unwrap();
+        assert_eq!(next.date, date(9, 25));
+        assert_eq!(next.name, "中秋节");
+        // 中秋假期中，下一个假期是国庆节
+        assert_eq!(
+            calendar.next_holiday(date(9, 26)).unwrap().date,
+            date(10, 1)
+        );
+        // 跨年：2026 年最后一个假期之后是 2027 年的元旦
+        let next = calendar.next_holiday(date(10, 19)).unwrap();
+        assert_eq!(next.date, NaiveDate::from_ymd_opt(2027, 1, 1).unwrap());
+        assert!(calendar.years_without_data().is_empty());
+        assert!(calendar.has_data(2027));
+    }
+
+    #[test]
+    fn test_workdays_without_data() {
+        let calendar = WorkdayCalendar::new(None, |_| None);
+        // 2026-10-16 是星期五
+        assert_eq!(calendar.next_workday(date(10, 16)), Some(date(10, 19)));
+        assert_eq!(calendar.workdays_between(date(10, 12), date(10, 25)), 10);
+        assert_eq!(calendar.years_without_data(), vec![2026]);
+        assert!(!calendar.has_data(2026));
+    }
+}
@@ -0,0 +1,2 @@
+//! riqi 与 riqi-data 共用的节假日数据文件结构
+pub mod modal;
@@ -1,3 +1,4 @@
+mod command;
 mod config;
 mod data;
 mod events;
@@ -7,19 +8,29 @@ mod theme;
 mod ui;
 mod utils;
 
+use crate::command::run_command;
 use crate::config::model::AppConfig;
 use crate::config::xdg::Xdg;
-use crate::events::{handle_goto_mode_key_event, handle_normal_mode_key_event, AppEvent};
+use crate::data::almanac::get_almanac;
+use crate::events::{
+    handle_almanac_mode_key_event, handle_goto_mode_key_event, handle_measure_mode_key_event,
+    handle_normal_mode_key_event, handle_plan_leave_mode_key_event, push_notification, AppEvent,
+};
+use crate::holiday::http::HttpClient;
+use crate::holiday::leave::plan_leave;
 use crate::holiday::manager::HolidayManager;
 use crate::holiday::modal::HolidayOfYearList;
+use crate::holiday::overrides::load_holiday_overrides;
 use crate::holiday::utils::get_ylc_code;
-use crate::state::{GotoPanelState, RiqiMode};
+use crate::holiday::workdays::WorkdayCalendar;
+use crate::state::{GotoPanelState, MeasureState, PlanLeaveState, RiqiMode};
+use crate::ui::almanac_panel_component::AlmanacPanelComponent;
 use crate::ui::bottom_line_component::BottomLineComponent;
 use crate::ui::goto_panel_component::GotoPanelComponent;
 use crate::ui::notification_component::NotificationComponent;
+use crate::ui::plan_leave_panel_component::PlanLeavePanelComponent;
 use crate::ui::translate::{get_translate, Language};
-use chrono::{Datelike, Local, NaiveDate};
-use clap::{arg, Parser};
+use chrono::{Datelike, Local};
 use color_eyre::Result;
 use config::{cli::Args, config_main::get_app_config};
 use crossterm::{
@@ -29,10 +40,9 @@ use crossterm::{
 };
 use data::calendar::MonthCalendar;
 use env_logger::{Builder, Target};
-use log::{debug, info, LevelFilter};
+use log::{info, LevelFilter};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Clear};
-use serde::Deserialize;
 use state::RiqiState;
 use std::collections::HashMap;
 use std::io::IsTerminal;
@@ -43,10 +53,7 @@ use std::{
     thread,
 };
 use theme::theme_loader::load_theme_from_file;
-use ui::{
-    layout::get_layout,
-    month_component::{self, MonthComponent},
-};
+use ui::{layout::get_layout, month_component::MonthComponent};
 
 fn setup_logger() {
     // 尝试在缓存目录创建日志文件
@@ -70,9 +77,14 @@ fn setup_logger() {
 }
 #[tokio::main]
 async fn main() -> Result<()> {
-    let args = Args::parse();
+    let mut args = Args::parse_args();
     setup_logger();
     color_eyre::install()?;
+    let command = args.command.take();
+    let app_config = get_app_config(args);
+    if let Some(command) = command {
+        return run_command(command, &app_config).await;
+    }
     // --- 1. 初始化终端 ---
     enable_raw_mode()?;
     // 使用 Box 包装，这样后端就不再关心具体是哪种流
@@ -90,10 +102,9 @@ async fn main() -> Result<()> {
     let (tx, rx) = mpsc::channel();
 
     let now = Local::now();
-    let app_config = get_app_config(args);
 
     let theme = load_theme_from_file(&app_config.theme)
-        .expect(&format!("Failed to load theme: {}", &app_config.theme));
+        .unwrap_or_else(|_| panic!("Failed to load theme: {}", &app_config.theme));
 
     let mut riqi_state = RiqiState {
         select_day: now.date_naive(),
@@ -107,18 +118,27 @@ async fn main() -> Result<()> {
             focus_inp: 0,
         },
         notification: vec![],
+        measure: MeasureState {
+            anchor: now.date_naive(),
+            workdays: 0,
+            next_workday: None,
+            offset: 0,
+            offset_workday: None,
+            years_without_data: vec![],
+        },
+        next_holiday: None,
+        plan_leave: PlanLeaveState {
+            year: now.year(),
+            days: 3,
+            plans: vec![],
+            selected: 0,
+            has_data: true,
+        },
     };
 
-    let now = Local::now();
     let mut holiday_map: HashMap<String, HolidayOfYearList> = HashMap::new();
-    let mut calendar = MonthCalendar::new(
-        now.year() as u32,
-        now.month(),
-        now.date_naive(),
-        &holiday_map,
-        &app_config.language,
-        &app_config.country,
-    );
+    let holiday_overrides = load_holiday_overrides();
+    let mut calendar = build_calendar(&riqi_state, &holiday_map, &app_config);
     // 事件源 A: 终端输入监听线程 (将 crossterm 事件转发到 mpsc)
     let tx_input = tx.clone();
     thread::spawn(move || loop {
@@ -128,28 +148,24 @@ async fn main() -> Result<()> {
             }
         }
     });
-    let holiday_manager = HolidayManager::new(tx.clone());
+    let holiday_manager = HolidayManager::new(tx.clone(), HttpClient::from_config(&app_config));
 
-    if app_config.show_holiday {
+    // 倒计时需要节假日数据，没有显示节假日时也要加载
+    if app_config.show_holiday || app_config.show_countdown {
         let current_year = riqi_state.select_day.year().to_string();
         let prev_year = (riqi_state.select_day.year() - 1).to_string();
         let next_year = (riqi_state.select_day.year() + 1).to_string();
-        let lang = app_config.language.clone();
-        let country = app_config.country.clone();
-        let source = app_config.source.clone();
+        let language_countries = app_config.holiday_language_countries();
+        let sources = app_config.sources.clone();
         let hm = holiday_manager.clone();
-        let tx_clone = tx.clone();
 
         tokio::spawn(async move {
-            let _ = hm
-                .load_ylc_holiday(&current_year, &lang, &country, source.clone())
-                .await;
-            let _ = hm
-                .load_ylc_holiday(&prev_year, &lang, &country, source.clone())
-                .await;
-            let _ = hm
-                .load_ylc_holiday(&next_year, &lang, &country, source)
-                .await;
+            for (lang, country) in &language_countries {
+                for year in [&current_year, &prev_year, &next_year] {
+                    hm.load_ylc_holiday(year, lang, country, sources.clone())
+                        .await;
+                }
+            }
         });
     }
 
@@ -168,90 +184,81 @@ async fn main() -> Result<()> {
                 if key.is_release() {
                     continue;
                 }
-                if key.code == KeyCode::Char('q') || key.code == KeyCode::Esc {
-                    if matches!(riqi_state.mode, RiqiMode::Normal) {
-                        break;
-                    }
+                if (key.code == KeyCode::Char('q') || key.code == KeyCode::Esc)
+                    && matches!(riqi_state.mode, RiqiMode::Normal)
+                {
+                    break;
                 }
-                if key.code == KeyCode::Enter {
-                    if matches!(riqi_state.mode, RiqiMode::Normal) {
-                        disable_raw_mode()?;
-                        // 
-                        if io::stdout().is_terminal() {
-                            stdout().execute(LeaveAlternateScreen)?;
-                        } else {
-                            stderr().execute(LeaveAlternateScreen)?;
-                        }
-                        print!("{}", riqi_state.select_day.format(&app_config.output));
-                        stdout().flush()?;
-                        return Ok(());
+                if key.code == KeyCode::Enter && matches!(riqi_state.mode, RiqiMode::Normal) {
+                    disable_raw_mode()?;
+                    //
+                    if io::stdout().is_terminal() {
+                        stdout().execute(LeaveAlternateScreen)?;
+                    } else {
+                        stderr().execute(LeaveAlternateScreen)?;
                     }
+                    print!("{}", riqi_state.select_day.format(&app_config.output));
+                    stdout().flush()?;
+                    return Ok(());
+                }
+
+                if key.code == KeyCode::Char('r')
+                    && matches!(riqi_state.mode, RiqiMode::Normal)
+                    && (app_config.show_holiday || app_config.show_countdown)
+                {
+                    holiday_manager
+                        .retry_failed(app_config.sources.clone())
+                        .await;
                 }
 
-                let pre_year = riqi_state.select_day.year();
-                let pre_month = riqi_state.select_day.month();
                 // 判断是什么mode
                 match riqi_state.mode {
                     RiqiMode::Normal => handle_normal_mode_key_event(key, &mut riqi_state),
                     RiqiMode::Goto => handle_goto_mode_key_event(key, &mut riqi_state, tx.clone()),
-                    _ => (),
+                    RiqiMode::Almanac => handle_almanac_mode_key_event(key, &mut riqi_state),
+                    RiqiMode::PlanLeave => handle_plan_leave_mode_key_event(key, &mut riqi_state),
+                    RiqiMode::Measure => {
+                        let workday_calendar = build_workday_calendar(&holiday_map, &app_config);
+                        let target = match key.code {
+                            KeyCode::Char(']') => {
+                                workday_calendar.next_workday(riqi_state.select_day)
+                            }
+                            KeyCode::Char('[') => {
+                                workday_calendar.prev_workday(riqi_state.select_day)
+                            }
+                            _ => None,
+                        };
+                        match target {
+                            Some(target) => riqi_state.select_day = target,
+                            None => handle_measure_mode_key_event(key, &mut riqi_state),
+                        }
+                    }
                 }
 
-                let selected_day = riqi_state.select_day;
-                let ylc_key = get_ylc_code(
-                    &selected_day.year().to_string(),
-                    &app_config.language,
-                    &app_config.country,
-                );
+                update_plan_leave(&mut riqi_state, &holiday_map, &app_config);
+
                 if app_config.show_holiday {
-                    // Check if we need to load adjacent years
+                    // 加载当前年份及相邻年份（跳转到较远的年份时当前年份也可能还没有加载）
                     let current_year = riqi_state.select_day.year();
-                    let prev_year = current_year - 1;
-                    let next_year = current_year + 1;
-
-                    // Check if prev_year data exists, if not load it
-                    let prev_ylc_key = get_ylc_code(
-                        &prev_year.to_string(),
-                        &app_config.language,
-                        &app_config.country,
-                    );
-                    if !holiday_map.contains_key(&prev_ylc_key) {
-                        holiday_manager
-                            .load_ylc_holiday(
-                                &prev_year.to_string(),
-                                &app_config.language,
-                                &app_config.country,
-                                app_config.source.clone(),
-                            )
-                            .await;
-                    }
-
-                    // Check if next_year data exists, if not load it
-                    let next_ylc_key = get_ylc_code(
-                        &next_year.to_string(),
-                        &app_config.language,
-                        &app_config.country,
-                    );
-                    if !holiday_map.contains_key(&next_ylc_key) {
-                        holiday_manager
-                            .load_ylc_holiday(
-                                &next_year.to_string(),
-                                &app_config.language,
-                                &app_config.country,
-                                app_config.source.clone(),
-                            )
-                            .await;
+                    for (language, country) in app_config.holiday_language_countries() {
+                        for year in [current_year, current_year - 1, current_year + 1] {
+                            let ylc_key = get_ylc_code(&year.to_string(), &language, &country);
+                            if !holiday_map.contains_key(&ylc_key) {
+                                holiday_manager
+                                    .load_ylc_holiday(
+                                        &year.to_string(),
+                                        &language,
+                                        &country,
+                                        app_config.sources.clone(),
+                                    )
+                                    .await;
+                            }
+                        }
                     }
                 }
 
-                calendar = MonthCalendar::new(
-                    riqi_state.select_day.year() as u32,
-                    riqi_state.select_day.month(),
-                    riqi_state.select_day,
-                    &holiday_map,
-                    &app_config.language,
-                    &app_config.country,
-                );
+                calendar = build_calendar(&riqi_state, &holiday_map, &app_config);
+                update_measure(&mut riqi_state, &holiday_map, &app_config);
 
                 draw_ui(&mut terminal, &calendar, &riqi_state, &app_config)?;
             }
@@ -262,14 +269,32 @@ async fn main() -> Result<()> {
                         continue;
                     }
                 }
+                // 每次更新都重新合并用户的调整，version 保持下载数据的版本
+                let holiday_of_year =
+                    holiday_overrides.apply(&ylc_key, &app_config.country, holiday_of_year);
                 holiday_map.insert(ylc_key, holiday_of_year);
-                calendar = MonthCalendar::new(
-                    riqi_state.select_day.year() as u32,
-                    riqi_state.select_day.month(),
-                    riqi_state.select_day,
-                    &holiday_map,
-                    &app_config.language,
-                    &app_config.country,
+                update_measure(&mut riqi_state, &holiday_map, &app_config);
+                // 请假方案可能改变选中的日期，所以在生成日历之前计算
+                update_plan_leave(&mut riqi_state, &holiday_map, &app_config);
+                calendar = build_calendar(&riqi_state, &holiday_map, &app_config);
+                if app_config.show_countdown {
+                    riqi_state.next_holiday = build_workday_calendar(&holiday_map, &app_config)
+                        .next_holiday(riqi_state.today);
+                }
+                draw_ui(&mut terminal, &calendar, &riqi_state, &app_config)?;
+            }
+            AppEvent::HolidayLoadFailed(ylc_key) => {
+                info!("load holiday {} fail", ylc_key);
+                let language = app_config
+                    .language
+                    .parse::<Language>()
+                    .unwrap_or(Language::EN);
+                // 多个年份同时失败时只显示一条通知
+                push_notification(
+                    &mut riqi_state,
+                    "holiday_load_failed",
+                    get_translate(language).holiday_load_failed,
+                    tx.clone(),
                 );
                 draw_ui(&mut terminal, &calendar, &riqi_state, &app_config)?;
             }
@@ -298,6 +323,95 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+fn build_calendar(
+    riqi_state: &RiqiState,
+    holiday_map: &HashMap<String, HolidayOfYearList>,
+    app_config: &AppConfig,
+) -> MonthCalendar {
+    // 只开启倒计时时也会加载节假日数据，但日历中不显示
+    let empty_holiday_map = HashMap::new();
+    let holiday_map = if app_config.show_holiday {
+        holiday_map
+    } else {
+        &empty_holiday_map
+    };
+    let mut calendar = MonthCalendar::new(
+        riqi_state.select_day.year() as u32,
+        riqi_state.select_day.month(),
+        riqi_state.select_day,
+        holiday_map,
+        &app_config.language,
+        &app_config.country,
+    );
+    calendar.attach_overlays(holiday_map, &app_config.overlay_countries);
+    calendar
+}
+
+/// 使用已加载的主国家节假日数据的工作日日历。与 workdays 子命令一样，
+/// 规则生成的数据不包含调休，当作没有数据，这些年份只按周末计算
+fn build_workday_calendar<'a>(
+    holiday_map: &'a HashMap<String, HolidayOfYearList>,
+    app_config: &'a AppConfig,
+) -> WorkdayCalendar<'a> {
+    WorkdayCalendar::new(app_config.region.clone(), move |year| {
+        let ylc_key = get_ylc_code(&year.to_string(), &app_config.language, &app_config.country);
+        holiday_map
+            .get(&ylc_key)
+            .filter(|list| !list.is_generated())
+            .map(|list| list.to_holiday_map())
+    })
+}
+
+/// 测量模式下重新计算工作日数量和下一个工作日
+fn update_measure(
+    riqi_state: &mut RiqiState,
+    holiday_map: &HashMap<String, HolidayOfYearList>,
+    app_config: &AppConfig,
+) {
+    if !matches!(riqi_state.mode, RiqiMode::Measure) {
+        return;
+    }
+    let workday_calendar = build_workday_calendar(holiday_map, app_config);
+    riqi_state.measure.workdays =
+        workday_calendar.workdays_between(riqi_state.measure.anchor, riqi_state.select_day);
+    riqi_state.measure.next_workday = workday_calendar.next_workday(riqi_state.select_day);
+    riqi_state.measure.offset_workday =
+        workday_calendar.add_workdays(riqi_state.select_day, riqi_state.measure.offset);
+    riqi_state.measure.years_without_data = workday_calendar.years_without_data();
+}
+
+/// 请假规划面板打开时重新计算方案，并跳转到选中方案的第一天
+fn update_plan_leave(
+    riqi_state: &mut RiqiState,
+    holiday_map: &HashMap<String, HolidayOfYearList>,
+    app_config: &AppConfig,
+) {
+    if !matches!(riqi_state.mode, RiqiMode::PlanLeave) {
+        return;
+    }
+    let workday_calendar = build_workday_calendar(holiday_map, app_config);
+    let plan_leave_state = &mut riqi_state.plan_leave;
+    plan_leave_state.plans = plan_leave(
+        &workday_calendar,
+        plan_leave_state.year,
+        plan_leave_state.days,
+        5,
+    );
+    // 与 plan-leave 子命令一样，只按周末规划没有意义，没有这一年的数据时不给出方案
+    plan_leave_state.has_data = !workday_calendar
+        .years_without_data()
+        .contains(&plan_leave_state.year);
+    if !plan_leave_state.has_data {
+        plan_leave_state.plans.clear();
+    }
+    plan_leave_state.selected = plan_leave_state
+        .selected
+        .min(plan_leave_state.plans.len().saturating_sub(1));
+    if let Some(plan) = plan_leave_state.selected_plan() {
+        riqi_state.select_day = plan.start;
+    }
+}
+
 // 将渲染逻辑抽离
 fn draw_ui<W: io::Write>(
     terminal: &mut Terminal<CrosstermBackend<W>>,
@@ -315,7 +429,7 @@ fn draw_ui<W: io::Write>(
 
         let frame_area = f.area();
         let layout = get_layout(frame_area, app_config.column, app_config.row);
-        let month_item = MonthComponent::new(calendar, &layout, &riqi_state, app_config);
+        let month_item = MonthComponent::new(calendar, &layout, riqi_state, app_config);
         month_item.render(layout.month_calendar.area, f.buffer_mut());
         let bottom_line = BottomLineComponent {
             app_config,
@@ -327,6 +441,14 @@ fn draw_ui<W: io::Write>(
             draw_goto_panel(riqi_state, app_config, f);
         }
 
+        if matches!(riqi_state.mode, RiqiMode::Almanac) {
+            draw_almanac_panel(riqi_state, app_config, f);
+        }
+
+        if matches!(riqi_state.mode, RiqiMode::PlanLeave) {
+            draw_plan_leave_panel(riqi_state, app_config, f);
+        }
+
         if !riqi_state.notification.is_empty() {
             let notification_component = NotificationComponent {
                 notifications: &riqi_state.notification,
@@ -366,3 +488,46 @@ fn draw_goto_panel(riqi_state: &RiqiState, app_config: &AppConfig, f: &mut Frame
     );
     goto_panel.render(popup_area, f.buffer_mut());
 }
+
+fn draw_almanac_panel(riqi_state: &RiqiState, app_config: &AppConfig, f: &mut Frame) {
+    let language = app_config
+        .language
+        .parse::<Language>()
+        .unwrap_or(Language::EN);
+    let translate = get_translate(language);
+
+    let almanac_panel = AlmanacPanelComponent {
+        date: riqi_state.select_day,
+        almanac: get_almanac(riqi_state.select_day),
+        translate,
+    };
+    let area = f.area();
+    let popup_area = area.centered(Constraint::Length(60), Constraint::Length(14));
+    f.render_widget(Clear, popup_area);
+    almanac_panel.render(popup_area, f.buffer_mut());
+}
+
+fn draw_plan_leave_panel(riqi_state: &RiqiState, app_config: &AppConfig, f: &mut Frame) {
+    let language = app_config
+        .language
+        .parse::<Language>()
+        .unwrap_or(Language::EN);
+    let translate = get_translate(language);
+
+    let plan_leave_panel = PlanLeavePanelComponent {
+        state: &riqi_state.plan_leave,
+        translate,
+    };
+    // 面板放在底部，不遮挡日历中高亮的请假日期
+    let area = f.area();
+    let height = (riqi_state.plan_leave.plans.len() as u16 + 3).min(area.height);
+    let width = area.width.min(80);
+    let popup_area = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + area.height.saturating_sub(height + 1),
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+    plan_leave_panel.render(popup_area, f.buffer_mut());
+}
@@ -0,0 +1,157 @@
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum PrimaryType {
+    #[serde(rename = "Substitute holiday")]
+    SubstituteHoliday,
+    #[serde(rename = "National holiday")]
+    NationalHoliday,
+    #[serde(rename = "Common holiday")]
+    CommonHoliday,
+    #[serde(rename = "Working Day on a Weekend")]
+    WorkingDayOnWeekend,
+    #[serde(rename = "Observance")]
+    Observance,
+    #[serde(rename = "Season")]
+    Season,
+    #[serde(rename = "Half day holiday")]
+    HalfDayHoliday,
+    #[serde(rename = "Regional holiday")]
+    RegionalHoliday,
+    /// 用户在 holiday_overrides.toml 中自定义的类型，rest 表示是否放假
+    #[serde(untagged)]
+    Custom { name: String, rest: bool },
+    /// 新版本数据中新增的类型，按普通纪念日显示，避免整年的数据解析失败
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl PrimaryType {
+    /// 类型的显示名称，与数据文件中的字符串一致
+    pub fn name(&self) -> String {
+        match self {
+            PrimaryType::Custom { name, .. } => name.clone(),
+            PrimaryType::Unknown(name) => name.clone(),
+            _ => serde_json::to_value(self)
+                .ok()
+                .and_then(|value| value.as_str().map(String::from))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// 是否为数据文件中定义的类型
+    pub fn is_known(&self) -> bool {
+        !matches!(self, PrimaryType::Custom { .. } | PrimaryType::Unknown(_))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Holiday {
+    pub name: String,
+    pub date: Date,
+    #[serde(rename = "type")]
+    pub holiday_type: Vec<String>,
+    pub primary_type: PrimaryType,
+    /// 地区性节日适用的 ISO 3166-2 行政区代码，例如 "DE-BY"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdivisions: Option<Vec<String>>,
+}
+
+impl Holiday {
+    pub fn new(
+        name: &str,
+        date: NaiveDate,
+        primary_type: PrimaryType,
+        subdivisions: Option<Vec<String>>,
+    ) -> Self {
+        Holiday {
+            name: name.to_string(),
+            date: Date {
+                iso: date.format("%Y-%m-%d").to_string(),
+                datetime: DateTime {
+                    year: date.year(),
+                    month: date.month() as i32,
+                    day: date.day() as i32,
+                    hour: None,
+                    minute: None,
+                    second: None,
+                },
+            },
+            holiday_type: vec![primary_type.name()],
+            primary_type,
+            subdivisions,
+        }
+    }
+
+    /// 节日是否属于指定的行政区，没有设置行政区或节日没有行政区信息时返回 false
+    pub fn is_in_region(&self, region: Option<&str>) -> bool {
+        match (region, &self.subdivisions) {
+            (Some(region), Some(subdivisions)) => subdivisions
+                .iter()
+                .any(|subdivision| subdivision.eq_ignore_ascii_case(region)),
+            _ => false,
+        }
+    }
+
+    /// 半天假开始放假的时间，如平安夜 12:00，没有时间时为全天
+    pub fn start_time(&self) -> Option<NaiveTime> {
+        let datetime = &self.date.datetime;
+        NaiveTime::from_hms_opt(
+            datetime.hour? as u32,
+            datetime.minute.unwrap_or(0) as u32,
+            datetime.second.unwrap_or(0) as u32,
+        )
+    }
+
+    /// 是否需要显示该节日：设置了行政区时隐藏其他行政区的地区性节日
+    pub fn is_visible_in_region(&self, region: Option<&str>) -> bool {
+        region.is_none() || self.subdivisions.is_none() || self.is_in_region(region)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Date {
+    pub iso: String,
+    pub datetime: DateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DateTime {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hour: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minute: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub second: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HolidayOfYearList {
+    pub version: i32,
+    pub holidays: Vec<Holiday>,
+}
+
+impl HolidayOfYearList {
+    /// 规则生成或没有任何数据时的空列表 (version 0)，不包含调休，不能用来计算工作日
+    pub fn is_generated(&self) -> bool {
+        self.version == 0
+    }
+
+    pub fn to_holiday_map(&self) -> HashMap<String, Vec<Holiday>> {
+        let mut date_map: HashMap<String, Vec<Holiday>> = HashMap::new();
+        for holiday in &self.holidays {
+            let date_key = holiday.date.iso.clone();
+            date_map.entry(date_key).or_default().push(holiday.clone());
+        }
+        date_map
+    }
+}
+
+pub fn parse_holidays_of_year(json_str: &str) -> Result<HolidayOfYearList, serde_json::Error> {
+    serde_json::from_str(json_str)
+}
@@ -1,12 +1,17 @@
-use crate::config::model::AppConfig;
+use crate::holiday::leave::LeavePlan;
+use crate::holiday::workdays::NextHoliday;
 use crate::theme::theme_model::Theme;
 use chrono::NaiveDate;
-use serde::Deserialize;
 
 #[derive(Debug)]
 pub enum RiqiMode {
     Normal,
     Goto,
+    Almanac,
+    /// 测量模式：计算开始测量的日期到选中日期之间的工作日
+    Measure,
+    /// 请假规划面板
+    PlanLeave,
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +28,39 @@ pub struct GotoPanelState {
     pub focus_inp: u8,
 }
 
+#[derive(Debug)]
+pub struct MeasureState {
+    /// 开始测量时选中的日期
+    pub anchor: NaiveDate,
+    /// anchor 与选中日期之间 (包含两端) 的工作日数量
+    pub workdays: u64,
+    /// 选中日期之后的第一个工作日
+    pub next_workday: Option<NaiveDate>,
+    /// 选中日期之后 (负数为之前) 第几个工作日，用 +,- 调整
+    pub offset: i64,
+    /// 选中日期之后 (之前) 第 offset 个工作日
+    pub offset_workday: Option<NaiveDate>,
+    /// 计算时用到的没有节假日数据的年份，这些年份只按周末计算
+    pub years_without_data: Vec<i32>,
+}
+
+#[derive(Debug)]
+pub struct PlanLeaveState {
+    pub year: i32,
+    /// 每次最多请假的天数
+    pub days: usize,
+    pub plans: Vec<LeavePlan>,
+    pub selected: usize,
+    /// 这一年是否有缓存或内置的节假日数据，没有时不规划
+    pub has_data: bool,
+}
+
+impl PlanLeaveState {
+    pub fn selected_plan(&self) -> Option<&LeavePlan> {
+        self.plans.get(self.selected)
+    }
+}
+
 #[derive(Debug)]
 pub struct RiqiState {
     pub select_day: NaiveDate,
@@ -31,4 +69,8 @@ pub struct RiqiState {
     pub mode: RiqiMode,
     pub goto_panel: GotoPanelState,
     pub notification: Vec<NotificationMessage>,
+    pub measure: MeasureState,
+    /// 今天之后的下一个假期，节假日数据更新时重新计算
+    pub next_holiday: Option<NextHoliday>,
+    pub plan_leave: PlanLeaveState,
 }
@@ -25,9 +25,9 @@ fn parse_color(s: &str) -> Result<Color, String> {
         let parts: Vec<_> = rgb.split(',').map(|x| x.trim().parse::<u8>()).collect();
         if parts.len() == 3 && parts.iter().all(|x| x.is_ok()) {
             return Ok(Color::Rgb(
-                parts[0].as_ref().unwrap().clone(),
-                parts[1].as_ref().unwrap().clone(),
-                parts[2].as_ref().unwrap().clone(),
+                *parts[0].as_ref().unwrap(),
+                *parts[1].as_ref().unwrap(),
+                *parts[2].as_ref().unwrap(),
             ));
         }
         return Err(format!("Rgb 格式错误: {}", s));
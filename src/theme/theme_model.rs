@@ -15,6 +15,9 @@ pub struct Theme {
     pub workday: ItemStyle,
     pub holiday_adjacent: ItemStyle,
     pub holiday: ItemStyle,
+    /// 半天假，没有设置时使用斜体的节假日颜色
+    #[serde(default)]
+    pub half_holiday: Option<ItemStyle>,
     pub bottom_line: ItemStyle,
 }
 
@@ -24,7 +27,7 @@ impl Theme {
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
 pub struct ItemStyle {
     #[serde(default, deserialize_with = "crate::theme::color_serde::de_opt_color")]
     pub bg: Option<Color>,
@@ -32,6 +35,9 @@ pub struct ItemStyle {
     pub fg: Option<Color>,
     pub bold: Option<bool>,
     pub italic: Option<bool>,
+    /// 暂未渲染，保留以兼容已有的主题文件
+    #[allow(dead_code)]
     pub under_line: Option<bool>,
+    #[allow(dead_code)]
     pub transparent_bg: Option<bool>,
 }
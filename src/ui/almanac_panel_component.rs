@@ -0,0 +1,101 @@
+use crate::data::almanac::Almanac;
+use crate::ui::translate::Translate;
+use chrono::NaiveDate;
+use ratatui::layout::Alignment;
+use ratatui::prelude::Buffer;
+use ratatui::style::{Color, Modifier};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{BorderType, Widget, Wrap};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Paragraph},
+};
+
+pub struct AlmanacPanelComponent<'a> {
+    pub date: NaiveDate,
+    pub almanac: Almanac,
+    pub translate: &'a Translate<'a>,
+}
+
+fn labeled_line<'a>(label: &'a str, value: String) -> Line<'a> {
+    Line::from(vec![
+        Span::styled(
+            format!("{}: ", label),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::from(value),
+    ])
+}
+
+impl<'a> Widget for AlmanacPanelComponent<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let outer_block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(format!(
+                " {} {} {}日 ",
+                self.translate.almanac,
+                self.date.format("%Y-%m-%d"),
+                self.almanac.sixty_cycle
+            ))
+            .title_alignment(Alignment::Center);
+
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let rows = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).split(inner_area);
+
+        let directions = self
+            .almanac
+            .directions
+            .iter()
+            .map(|(god, direction)| format!("{}{}", god, direction))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let translate = self.translate;
+        let lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("{}: ", translate.almanac_recommends),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::from(self.almanac.recommends.join(" ")),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    format!("{}: ", translate.almanac_avoids),
+                    Style::default().fg(Color::Red),
+                ),
+                Span::from(self.almanac.avoids.join(" ")),
+            ]),
+            labeled_line(translate.almanac_clash, self.almanac.clash),
+            labeled_line(
+                translate.almanac_twelve_star,
+                format!(
+                    "{}  {}: {}",
+                    self.almanac.twelve_star, translate.almanac_duty, self.almanac.duty
+                ),
+            ),
+            labeled_line(translate.almanac_directions, directions),
+            labeled_line(
+                translate.almanac_lucky_gods,
+                self.almanac.lucky_gods.join(" "),
+            ),
+            labeled_line(
+                translate.almanac_unlucky_gods,
+                self.almanac.unlucky_gods.join(" "),
+            ),
+        ];
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .render(rows[0], buf);
+
+        Paragraph::new(self.translate.almanac_help)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray))
+            .render(rows[1], buf);
+    }
+}
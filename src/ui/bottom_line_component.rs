@@ -1,7 +1,9 @@
 use crate::config::model::AppConfig;
-use crate::state::RiqiState;
-use crate::ui::translate::{get_translate, Language};
+use crate::data::almanac::get_almanac;
+use crate::state::{RiqiMode, RiqiState};
+use crate::ui::translate::{get_translate, Language, Translate};
 use crate::ui::utils::get_style_from_config;
+use chrono::NaiveDate;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::Widget;
@@ -14,6 +16,69 @@ pub struct BottomLineComponent<'a> {
     pub riqi_state: &'a RiqiState,
 }
 
+impl BottomLineComponent<'_> {
+    /// 距离下一个假期的天数，如 "国庆节还有 12 天"
+    fn countdown_text(&self, translate: &Translate) -> Option<String> {
+        if !self.app_config.show_countdown {
+            return None;
+        }
+        let next_holiday = self.riqi_state.next_holiday.as_ref()?;
+        let days = (next_holiday.date - self.riqi_state.today).num_days();
+        let template = if days == 1 {
+            translate.countdown_tomorrow
+        } else {
+            translate.countdown
+        };
+        Some(
+            template
+                .replace("{name}", &next_holiday.name)
+                .replace("{days}", &days.to_string()),
+        )
+    }
+
+    /// 测量模式：开始日期 ~ 选中日期之间的工作日数量和选中日期之后的第一个工作日
+    fn measure_line(&self, translate: &Translate) -> Line<'static> {
+        let measure = &self.riqi_state.measure;
+        let (from, to) = if measure.anchor <= self.riqi_state.select_day {
+            (measure.anchor, self.riqi_state.select_day)
+        } else {
+            (self.riqi_state.select_day, measure.anchor)
+        };
+        let format_date = |date: Option<NaiveDate>| {
+            date.map(|date| date.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "-".to_string())
+        };
+        let mut text = format!(
+            "{} ~ {} {}: {} | {}: {} | {:+} {}: {} | {}",
+            from.format("%Y-%m-%d"),
+            to.format("%Y-%m-%d"),
+            translate.workdays,
+            measure.workdays,
+            translate.next_workday,
+            format_date(measure.next_workday),
+            measure.offset,
+            translate.workdays,
+            format_date(measure.offset_workday),
+            translate.measure_help.replace(';', " | "),
+        );
+        // 用到了没有节假日数据的年份时，结果只按周末计算，放在最前面提示
+        if !measure.years_without_data.is_empty() {
+            let years = measure
+                .years_without_data
+                .iter()
+                .map(|year| year.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            text = format!(
+                "{} | {}",
+                translate.no_holiday_data.replace("{year}", &years),
+                text
+            );
+        }
+        Line::from(text)
+    }
+}
+
 impl<'a> Widget for BottomLineComponent<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let language = Language::from_str(&self.app_config.language);
@@ -22,32 +87,15 @@ impl<'a> Widget for BottomLineComponent<'a> {
             return;
         }
 
-        if let Ok(language) = language {
-            let translate = get_translate(language);
-            let line = Line::from(vec![
-                Span::from("h,j,k,l"),
-                Span::from(":"),
-                Span::from(translate.navigation),
-                Span::from(" | "),
-                Span::from("u"),
-                Span::from(":"),
-                Span::from(translate.prev_month),
-                Span::from(" | "),
-                Span::from("d"),
-                Span::from(":"),
-                Span::from(translate.next_month),
+        if self.app_config.show_almanac_summary && area.height >= 2 {
+            let almanac = get_almanac(self.riqi_state.select_day);
+            let translate = get_translate(*language.as_ref().unwrap_or(&Language::EN));
+            let summary = Line::from(vec![
+                Span::from(format!("{}: ", translate.almanac_recommends)),
+                Span::from(almanac.recommends.join(" ")),
                 Span::from(" | "),
-                Span::from("f"),
-                Span::from(":"),
-                Span::from(translate.next_year),
-                Span::from(" | "),
-                Span::from("b"),
-                Span::from(":"),
-                Span::from(translate.prev_year),
-                Span::from(" | "),
-                Span::from("t"),
-                Span::from(":"),
-                Span::from(translate.back_to_today),
+                Span::from(format!("{}: ", translate.almanac_avoids)),
+                Span::from(almanac.avoids.join(" ")),
             ])
             .centered()
             .style(get_style_from_config(
@@ -55,6 +103,71 @@ impl<'a> Widget for BottomLineComponent<'a> {
                 self.riqi_state.theme.bottom_line,
             ));
 
+            summary.render(
+                Rect {
+                    x: area.x,
+                    y: area.y + area.height - 2,
+                    width: area.width,
+                    height: 1,
+                },
+                buf,
+            );
+        }
+
+        if let Ok(language) = language {
+            let translate = get_translate(language);
+            let line = if matches!(self.riqi_state.mode, RiqiMode::Measure) {
+                self.measure_line(translate)
+            } else {
+                let mut spans = vec![
+                    Span::from("h,j,k,l"),
+                    Span::from(":"),
+                    Span::from(translate.navigation),
+                    Span::from(" | "),
+                    Span::from("u"),
+                    Span::from(":"),
+                    Span::from(translate.prev_month),
+                    Span::from(" | "),
+                    Span::from("d"),
+                    Span::from(":"),
+                    Span::from(translate.next_month),
+                    Span::from(" | "),
+                    Span::from("f"),
+                    Span::from(":"),
+                    Span::from(translate.next_year),
+                    Span::from(" | "),
+                    Span::from("b"),
+                    Span::from(":"),
+                    Span::from(translate.prev_year),
+                    Span::from(" | "),
+                    Span::from("t"),
+                    Span::from(":"),
+                    Span::from(translate.back_to_today),
+                    Span::from(" | "),
+                    Span::from("a"),
+                    Span::from(":"),
+                    Span::from(translate.almanac),
+                    Span::from(" | "),
+                    Span::from("p"),
+                    Span::from(":"),
+                    Span::from(translate.plan_leave),
+                    Span::from(" | "),
+                    Span::from("m"),
+                    Span::from(":"),
+                    Span::from(translate.measure),
+                ];
+                // 倒计时显示在按键提示之前
+                if let Some(countdown) = self.countdown_text(translate) {
+                    spans.splice(0..0, [Span::from(countdown), Span::from(" | ")]);
+                }
+                Line::from(spans)
+            }
+            .centered()
+            .style(get_style_from_config(
+                Some(Style::default()),
+                self.riqi_state.theme.bottom_line,
+            ));
+
             line.render(
                 Rect {
                     x: area.x,
@@ -1,14 +1,22 @@
 use super::utils::get_style_from_config;
 use crate::config::model::AppConfig;
-use crate::holiday::utils::get_holiday_state;
+use crate::data::moon_phase::moon_phase_of_day;
+use crate::data::period::period_markers;
+use crate::holiday::modal::Holiday;
+use crate::holiday::utils::{get_holiday_state, DayState};
+use crate::state::RiqiMode;
+use crate::theme::theme_model::Theme;
+use crate::ui::japanese::rokuyo;
 use crate::ui::lunar::{number_to_lunar_day, number_to_lunar_month};
+use crate::ui::translate::{get_translate, Language};
 use crate::{data::calendar::CalendarDay, state::RiqiState};
+use chrono::{Local, TimeZone};
 use ratatui::widgets::{Paragraph, Wrap};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::Style,
-    text::Line,
+    text::{Line, Span},
     widgets::{Block, BorderType, Borders, Widget},
 };
 
@@ -31,61 +39,65 @@ impl<'a> DayCell<'a> {
         }
     }
 
-    pub fn get_day_item_style(&self, is_holiday: bool) -> Style {
-        let mut style = self.riqi_state.theme.get_default_style();
-        if is_holiday {
-            // 周六日使用节假日颜色
-            if self.day_data.is_current_month {
-                style = get_style_from_config(Some(style), self.riqi_state.theme.holiday);
-            } else {
-                style = get_style_from_config(Some(style), self.riqi_state.theme.holiday_adjacent);
-            }
-        } else {
-            // 工作日使用工作颜色
-            if self.day_data.is_current_month {
-                style = get_style_from_config(Some(style), self.riqi_state.theme.workday);
-            } else {
-                style = get_style_from_config(Some(style), self.riqi_state.theme.workday_adjacent);
-            }
-        }
-
-        if self.day_data.is_today {
-            style = style.bold();
-        }
+    pub fn get_day_item_style(&self, day_state: DayState) -> Style {
+        get_day_item_style(&self.riqi_state.theme, self.day_data, day_state)
+    }
 
-        if self.day_data.is_select_day {
-            style = get_style_from_config(Some(style), self.riqi_state.theme.focus_day)
-        }
+    fn get_holiday_name_style(&self, holiday: &Holiday, style: Style) -> Style {
+        get_holiday_name_style(self.app_config, holiday, style)
+    }
 
-        style
+    /// 请假规划面板中选中方案的请假日期
+    fn is_suggested_leave_day(&self) -> bool {
+        matches!(self.riqi_state.mode, RiqiMode::PlanLeave)
+            && self
+                .riqi_state
+                .plan_leave
+                .selected_plan()
+                .is_some_and(|plan| plan.leave_days.contains(&self.day_data.date()))
     }
 
-    fn render_out_border(&self, is_rest_day: bool, area: Rect, buf: &mut Buffer) -> Rect {
+    fn render_out_border(&self, day_state: DayState, area: Rect, buf: &mut Buffer) -> Rect {
         let block = Block::new()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(self.get_day_item_style(is_rest_day).fg.unwrap()));
+            .border_style(Style::default().fg(self.get_day_item_style(day_state).fg.unwrap()));
         let inner_area = block.inner(area);
         block.render(area, buf);
         inner_area
     }
 
-    fn render_work_rest_icon(&self, is_rest_day: bool, area: Rect, buf: &mut Buffer, style: Style) {
-        let holiday_line = Line::from(if is_rest_day { "\u{f104f}" } else { "\u{f491}" })
-            .style(style)
-            .centered();
+    fn render_work_rest_icon(
+        &self,
+        day_state: DayState,
+        area: Rect,
+        buf: &mut Buffer,
+        style: Style,
+    ) {
+        let icon = match day_state {
+            DayState::Rest => "\u{f104f}",
+            DayState::Work => "\u{f491}",
+            DayState::HalfDay => "½",
+        };
+        let holiday_line = Line::from(icon).style(style).centered();
         holiday_line.render(area, buf);
     }
 
     fn render_content(
         &self,
-        is_rest_day: bool,
+        day_state: DayState,
         show_holiday_icon: bool,
         inner_area: Rect,
         buf: &mut Buffer,
     ) {
-        let day_item_style = self.get_day_item_style(is_rest_day);
-        let line = Line::from(self.day_data.day.to_string()).style(day_item_style);
+        let day_item_style = self.get_day_item_style(day_state);
+        let is_suggested_leave_day = self.is_suggested_leave_day();
+        let day_style = if is_suggested_leave_day {
+            day_item_style.reversed()
+        } else {
+            day_item_style
+        };
+        let line = Line::from(self.day_data.day.to_string()).style(day_style);
         line.render(
             Rect {
                 x: inner_area.left() + 1,
@@ -96,12 +108,13 @@ impl<'a> DayCell<'a> {
             buf,
         );
 
-        let mut icon_x = inner_area.left() + inner_area.width - 3;
+        // 图标从右往左排列，窄的格子里可能放不下，不能越过日期数字 (left+1..left+3)
+        let mut icon_x = inner_area.right().saturating_sub(3);
 
         let show_holiday = self.app_config.show_holiday;
         if show_holiday_icon && show_holiday {
             self.render_work_rest_icon(
-                is_rest_day,
+                day_state,
                 Rect {
                     x: icon_x,
                     y: inner_area.top(),
@@ -111,7 +124,7 @@ impl<'a> DayCell<'a> {
                 buf,
                 day_item_style,
             );
-            icon_x -= 2;
+            icon_x = icon_x.saturating_sub(2);
         }
 
         if self.day_data.is_today {
@@ -125,20 +138,132 @@ impl<'a> DayCell<'a> {
                 },
                 buf,
             );
-            icon_x -= 2;
+            icon_x = icon_x.saturating_sub(2);
+        }
+
+        let moon_phase = self.app_config.show_moon_phase.then(|| {
+            let date = self.day_data.date();
+            // 按这一天自身的时区偏移计算，跨夏令时切换的月份也能对得上；
+            // 取正午是为了避开午夜落在夏令时空档里的地区
+            let offset = Local
+                .offset_from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap())
+                .earliest()
+                .unwrap_or_else(|| *Local::now().offset());
+            moon_phase_of_day(date, offset)
+        });
+        if let Some(moon_phase) = moon_phase.filter(|_| icon_x >= inner_area.left() + 3) {
+            let moon_line = Line::from(moon_phase.phase.glyph(self.app_config.moon_phase_glyph))
+                .style(day_item_style)
+                .centered();
+            moon_line.render(
+                Rect {
+                    x: icon_x,
+                    y: inner_area.top(),
+                    width: 2,
+                    height: 1,
+                },
+                buf,
+            );
         }
 
+        self.render_overlay_badges(
+            Rect {
+                x: inner_area.left() + 4,
+                y: inner_area.top(),
+                width: icon_x.saturating_sub(inner_area.left() + 4),
+                height: 1,
+            },
+            buf,
+        );
+
         let mut content_lines: Vec<Line> = vec![];
 
+        if is_suggested_leave_day {
+            let language = self
+                .app_config
+                .language
+                .parse::<Language>()
+                .unwrap_or(Language::EN);
+            content_lines.push(Line::from(get_translate(language).leave_day).style(
+                get_style_from_config(Some(day_item_style), self.riqi_state.theme.holiday),
+            ));
+        }
+
         if self.app_config.show_lunar {
             content_lines.push(self.get_lunar_line(day_item_style));
+            for marker in period_markers(self.day_data.date()) {
+                content_lines.push(Line::from(marker).style(day_item_style));
+            }
+        }
+
+        if self.app_config.show_rokuyo {
+            let rokuyo = rokuyo(self.day_data.lunar_month, self.day_data.lunar_day);
+            content_lines.push(Line::from(rokuyo).style(day_item_style));
+        }
+
+        // 选中的日期额外显示月面被照亮的比例
+        if let Some(moon_phase) = moon_phase.filter(|_| self.day_data.is_select_day) {
+            content_lines.push(
+                Line::from(format!(
+                    "{} {}%",
+                    moon_phase.phase.glyph(self.app_config.moon_phase_glyph),
+                    moon_phase.illumination
+                ))
+                .style(day_item_style),
+            );
+        }
+
+        for label in self
+            .day_data
+            .alternate_labels(&self.app_config.alternate_calendars)
+        {
+            content_lines.push(Line::from(label).style(day_item_style));
         }
 
         if let Some(holidays) = &self.day_data.holidays {
             if show_holiday {
-                for holiday in holidays {
-                    let holiday_name = holiday.name.clone();
-                    content_lines.push(Line::from(holiday_name).style(day_item_style))
+                for holiday in holidays.iter().filter(|holiday| {
+                    holiday.is_visible_in_region(self.app_config.region.as_deref())
+                        && self.app_config.holiday_types.is_visible(holiday)
+                }) {
+                    // 半天假显示开始放假的时间
+                    let holiday_name = match holiday.start_time() {
+                        Some(start_time) if day_state == DayState::HalfDay => {
+                            format!("{} {}~", holiday.name, start_time.format("%H:%M"))
+                        }
+                        _ => holiday.name.clone(),
+                    };
+                    content_lines.push(
+                        Line::from(holiday_name)
+                            .style(self.get_holiday_name_style(holiday, day_item_style)),
+                    )
+                }
+            }
+        }
+
+        // 选中的日期额外显示叠加国家的节日名称
+        if self.day_data.is_select_day && show_holiday {
+            for (overlay, holidays) in self
+                .app_config
+                .overlay_countries
+                .iter()
+                .zip(&self.day_data.overlay_holidays)
+            {
+                for holiday in holidays
+                    .iter()
+                    .flatten()
+                    .filter(|holiday| self.app_config.holiday_types.is_visible(holiday))
+                {
+                    content_lines.push(Line::from(vec![
+                        Span::styled(
+                            format!("{} ", overlay.marker),
+                            day_item_style.fg(overlay.color),
+                        ),
+                        Span::styled(
+                            holiday.name.clone(),
+                            self.get_holiday_name_style(holiday, day_item_style),
+                        ),
+                    ]));
                 }
             }
         }
@@ -148,14 +273,39 @@ impl<'a> DayCell<'a> {
             Rect {
                 x: inner_area.left() + 1,
                 y: inner_area.top() + 1,
-                width: inner_area.width - 2,
-                height: inner_area.height - 1,
+                width: inner_area.width.saturating_sub(2),
+                height: inner_area.height.saturating_sub(1),
             },
             buf,
         );
     }
 
-    pub fn get_lunar_line(&self, style: Style) -> Line {
+    /// 在日期旁边显示当天放假的叠加国家标记
+    fn render_overlay_badges(&self, area: Rect, buf: &mut Buffer) {
+        if !self.app_config.show_holiday || area.width == 0 {
+            return;
+        }
+        let mut spans: Vec<Span> = vec![];
+        for (overlay, holidays) in self
+            .app_config
+            .overlay_countries
+            .iter()
+            .zip(&self.day_data.overlay_holidays)
+        {
+            let (day_state, is_holiday) =
+                get_holiday_state(holidays, self.day_data.day_of_week as u16, None);
+            if day_state.is_rest() && is_holiday {
+                spans.push(Span::styled(
+                    overlay.marker.clone(),
+                    Style::default().fg(overlay.color).bold(),
+                ));
+                spans.push(Span::from(" "));
+            }
+        }
+        Line::from(spans).render(area, buf);
+    }
+
+    pub fn get_lunar_line(&self, style: Style) -> Line<'_> {
         // 显示农历日期
         let lunar_day = if self.day_data.lunar_day == 1 {
             // 如果是初一，显示月份
@@ -169,11 +319,136 @@ impl<'a> DayCell<'a> {
     }
 }
 
+/// 按放假状态、是否当前月份、今天和选中的日期计算日期的样式
+pub fn get_day_item_style(theme: &Theme, day_data: &CalendarDay, day_state: DayState) -> Style {
+    let mut style = theme.get_default_style();
+    if day_state == DayState::HalfDay {
+        style = match (theme.half_holiday, day_data.is_current_month) {
+            (Some(half_holiday), _) => get_style_from_config(Some(style), half_holiday),
+            (None, true) => get_style_from_config(Some(style), theme.holiday).italic(),
+            (None, false) => get_style_from_config(Some(style), theme.holiday_adjacent).italic(),
+        };
+    } else if day_state.is_rest() {
+        // 周六日使用节假日颜色
+        if day_data.is_current_month {
+            style = get_style_from_config(Some(style), theme.holiday);
+        } else {
+            style = get_style_from_config(Some(style), theme.holiday_adjacent);
+        }
+    } else {
+        // 工作日使用工作颜色
+        if day_data.is_current_month {
+            style = get_style_from_config(Some(style), theme.workday);
+        } else {
+            style = get_style_from_config(Some(style), theme.workday_adjacent);
+        }
+    }
+
+    if day_data.is_today {
+        style = style.bold();
+    }
+
+    if day_data.is_select_day {
+        style = get_style_from_config(Some(style), theme.focus_day)
+    }
+
+    style
+}
+
+/// 按节日类型的显示设置调整节日名称的样式
+pub fn get_holiday_name_style(app_config: &AppConfig, holiday: &Holiday, style: Style) -> Style {
+    match app_config.holiday_types.get(&holiday.primary_type) {
+        Some(config) => {
+            let style = get_style_from_config(Some(style), config.style);
+            if config.dim {
+                style.dim()
+            } else {
+                style
+            }
+        }
+        None => style,
+    }
+}
+
 impl Widget for DayCell<'_> {
     fn render(self, area: Rect, buf: &mut ratatui::prelude::Buffer) {
-        let (is_rest_day, show_holiday_icon) =
-            get_holiday_state(&self.day_data.holidays, self.day_data.day_of_week as u16);
-        let inner_area = self.render_out_border(is_rest_day, area, buf);
-        self.render_content(is_rest_day, show_holiday_icon, inner_area, buf);
+        let (day_state, show_holiday_icon) = get_holiday_state(
+            &self.day_data.holidays,
+            self.day_data.day_of_week as u16,
+            self.app_config.region.as_deref(),
+        );
+        let inner_area = self.render_out_border(day_state, area, buf);
+        self.render_content(day_state, show_holiday_icon, inner_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holiday::modal::PrimaryType;
+    use crate::state::{GotoPanelState, MeasureState, PlanLeaveState};
+    use crate::theme::theme_loader::load_theme_from_file;
+    use chrono::NaiveDate;
+
+    fn riqi_state(today: NaiveDate) -> RiqiState {
+        RiqiState {
+            select_day: today,
+            today,
+            theme: load_theme_from_file("ningmen").unwrap(),
+            mode: RiqiMode::Normal,
+            goto_panel: GotoPanelState {
+                year: 2026,
+                month: 10,
+                day: 1,
+                focus_inp: 0,
+            },
+            notification: vec![],
+            measure: MeasureState {
+                anchor: today,
+                workdays: 0,
+                next_workday: None,
+                offset: 0,
+                offset_workday: None,
+                years_without_data: vec![],
+            },
+            next_holiday: None,
+            plan_leave: PlanLeaveState {
+                year: 2026,
+                days: 3,
+                plans: vec![],
+                selected: 0,
+                has_data: true,
+            },
+        }
+    }
+
+    /// 今天又是节假日时有三个图标，窄的格子里月相不能覆盖日期数字，也不能溢出
+    #[test]
+    fn test_narrow_cell_keeps_day_number() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let holidays = vec![Holiday::new(
+            "国庆节",
+            today,
+            PrimaryType::NationalHoliday,
+            None,
+        )];
+        let day = CalendarDay::new(2026, 10, 1, 4, true, true, today, Some(&holidays));
+        let state = riqi_state(today);
+        let config = AppConfig {
+            show_moon_phase: true,
+            ..AppConfig::for_test()
+        };
+        for width in [9, 7, 5, 3] {
+            let area = Rect::new(0, 0, width, 4);
+            let mut buf = Buffer::empty(area);
+            DayCell::new(&day, &state, &config).render(area, &mut buf);
+            if width >= 5 {
+                assert_eq!(buf[(2, 1)].symbol(), "1", "width {}", width);
+            }
+            if width == 9 {
+                // 第三个图标位置在 inner_area.left()，放不下月相
+                assert_eq!(buf[(1, 1)].symbol(), " ");
+            }
+        }
     }
 }
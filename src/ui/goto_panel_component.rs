@@ -1,13 +1,11 @@
 use crate::ui::translate::Translate;
 use ratatui::layout::Alignment;
 use ratatui::prelude::Buffer;
-use ratatui::widgets::BorderType::Rounded;
 use ratatui::widgets::{BorderType, Widget};
 use ratatui::{
-    layout::{Constraint, Flex, Layout, Rect},
-    style::{Color, Style, Stylize},
-    widgets::{Block, Borders, Clear, Paragraph},
-    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
 };
 
 pub struct GotoPanelComponent<'a> {
@@ -0,0 +1,63 @@
+use chrono::{Datelike, NaiveDate};
+
+/// 日本年号及其开始日期（公历）
+const ERAS: [(&str, i32, u32, u32); 5] = [
+    ("令和", 2019, 5, 1),
+    ("平成", 1989, 1, 8),
+    ("昭和", 1926, 12, 25),
+    ("大正", 1912, 7, 30),
+    ("明治", 1868, 1, 25),
+];
+
+const ROKUYO: [&str; 6] = ["大安", "赤口", "先勝", "友引", "先負", "仏滅"];
+
+/// 返回日期对应的和历年份，例如 "令和8年"，第一年显示为 "元年"
+pub fn japanese_era_year(date: NaiveDate) -> Option<String> {
+    ERAS.iter().find_map(|(name, year, month, day)| {
+        let start = NaiveDate::from_ymd_opt(*year, *month, *day)?;
+        if date < start {
+            return None;
+        }
+        let era_year = date.year() - year + 1;
+        if era_year == 1 {
+            Some(format!("{}元年", name))
+        } else {
+            Some(format!("{}{}年", name, era_year))
+        }
+    })
+}
+
+/// 根据农历月日计算六曜，闰月按本月计算
+pub fn rokuyo(lunar_month: i32, lunar_day: i32) -> &'static str {
+    let index = (lunar_month.abs() + lunar_day).rem_euclid(6) as usize;
+    ROKUYO[index]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_japanese_era_year() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        assert_eq!(japanese_era_year(date).unwrap(), "令和8年");
+        let date = NaiveDate::from_ymd_opt(2019, 5, 1).unwrap();
+        assert_eq!(japanese_era_year(date).unwrap(), "令和元年");
+        let date = NaiveDate::from_ymd_opt(2019, 4, 30).unwrap();
+        assert_eq!(japanese_era_year(date).unwrap(), "平成31年");
+        let date = NaiveDate::from_ymd_opt(1989, 1, 1).unwrap();
+        assert_eq!(japanese_era_year(date).unwrap(), "昭和64年");
+        let date = NaiveDate::from_ymd_opt(1800, 1, 1).unwrap();
+        assert!(japanese_era_year(date).is_none());
+    }
+
+    #[test]
+    fn test_rokuyo() {
+        assert_eq!(rokuyo(1, 1), "先勝");
+        assert_eq!(rokuyo(2, 1), "友引");
+        assert_eq!(rokuyo(6, 1), "赤口");
+        assert_eq!(rokuyo(4, 1), "仏滅");
+        assert_eq!(rokuyo(5, 1), "大安");
+        assert_eq!(rokuyo(-6, 1), "赤口");
+    }
+}
@@ -4,8 +4,6 @@ use ratatui::layout::{Constraint, Flex, Layout, Rect};
 pub struct MonthCalendarLayout {
     pub area: Rect,
     pub title: Rect,
-    pub head: Rect,
-    pub content: Rect,
     pub day_item_row: u32,
     pub day_item_column: u32,
     pub day_gap: u32,
@@ -13,7 +11,6 @@ pub struct MonthCalendarLayout {
 
 #[derive(Debug)]
 pub struct RiqiLayout {
-    pub title: Rect,
     pub month_calendar: MonthCalendarLayout,
     pub bottom_line: Rect,
 }
@@ -66,18 +63,6 @@ pub fn get_layout(
             width: month_cal_center_area.width,
             height: month_til_height,
         },
-        head: Rect {
-            x: month_cal_center_area.x,
-            y: month_cal_center_area.y + month_til_height,
-            width: month_cal_center_area.width,
-            height: month_head_height,
-        },
-        content: Rect {
-            x: month_cal_center_area.x,
-            y: month_cal_center_area.y + month_til_height + month_head_height,
-            width: month_cal_center_area.width,
-            height: month_cal_center_area.height - month_til_height - month_head_height,
-        },
         day_item_column: (month_cal_center_area.width as u32 - (6 * day_item_gap)) / 7,
         day_item_row: (month_cal_center_area.height as u32
             - month_til_height as u32
@@ -86,12 +71,6 @@ pub fn get_layout(
         day_gap: day_item_gap,
     };
     let riqi_layout = RiqiLayout {
-        title: Rect {
-            x: 0,
-            y: 0,
-            width: 0,
-            height: 0,
-        },
         month_calendar,
         bottom_line: *main_rows.get(1).unwrap(),
     };
@@ -5,8 +5,11 @@ pub mod utils;
 pub mod week_row;
 
 pub mod bottom_line_component;
+pub mod japanese;
 pub mod lunar;
 pub mod translate;
 
+pub mod almanac_panel_component;
 pub mod goto_panel_component;
 pub mod notification_component;
+pub mod plan_leave_panel_component;
@@ -75,7 +75,6 @@ impl<'a> MonthComponent<'a> {
 impl<'a> Widget for MonthComponent<'a> {
     fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
         let day_height = self.riqi_layout.month_calendar.day_item_row as u16;
-        let day_width = self.riqi_layout.month_calendar.day_item_column as u16;
         self.render_month_til(buf);
         self.render_month_header_row(area, buf);
         for (week_idx, week) in self.data.day_data.iter().enumerate() {
@@ -1,8 +1,9 @@
 use crate::state::NotificationMessage;
 use ratatui::prelude::Buffer;
+use ratatui::text::Span;
 use ratatui::widgets::Widget;
 use ratatui::{
-    layout::{Constraint, Layout, Rect},
+    layout::Rect,
     style::{Color, Style},
     widgets::{Block, BorderType, Borders, Paragraph},
 };
@@ -13,12 +14,13 @@ pub struct NotificationComponent<'a> {
 
 impl<'a> Widget for NotificationComponent<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let max_width = 20;
-        let notification_height = 3;
-        let spacing = 0;
+        let max_width = 30;
+        let mut y_offset = 0;
 
-        for (i, notification) in self.notifications.iter().enumerate() {
-            let y_offset = i as u16 * (notification_height + spacing);
+        for notification in self.notifications {
+            let width = max_width.min(area.width);
+            let lines = wrap_by_width(&notification.message, width.saturating_sub(2) as usize);
+            let notification_height = lines.len() as u16 + 2;
 
             if area.y + y_offset + notification_height > area.bottom() {
                 break;
@@ -27,9 +29,10 @@ impl<'a> Widget for NotificationComponent<'a> {
             let notification_area = Rect {
                 x: area.right().saturating_sub(max_width + 1),
                 y: area.y + y_offset + 1,
-                width: max_width.min(area.width),
+                width,
                 height: notification_height,
             };
+            y_offset += notification_height;
 
             let block = Block::default()
                 .borders(Borders::ALL)
@@ -40,17 +43,37 @@ impl<'a> Widget for NotificationComponent<'a> {
             let inner_area = block.inner(notification_area);
             block.render(notification_area, buf);
 
-            let lines: Vec<String> = notification
-                .message
-                .chars()
-                .collect::<Vec<char>>()
-                .chunks(inner_area.width as usize)
-                .map(|chunk| chunk.iter().collect())
-                .collect();
-
             let text = lines.join("\n");
             let paragraph = Paragraph::new(text).style(Style::default().fg(Color::Red));
             paragraph.render(inner_area, buf);
         }
     }
 }
+
+/// 按显示宽度折行，中日韩文字占两列
+fn wrap_by_width(text: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+    for c in text.chars() {
+        if !line.is_empty() && Span::raw(format!("{}{}", line, c)).width() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        line.push(c);
+    }
+    lines.push(line);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_by_width() {
+        assert_eq!(wrap_by_width("invalid date", 8), vec!["invalid ", "date"]);
+        assert_eq!(
+            wrap_by_width("节假日数据下载", 6),
+            vec!["节假日", "数据下", "载"]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::state::PlanLeaveState;
+use crate::ui::translate::Translate;
+use ratatui::layout::Alignment;
+use ratatui::prelude::Buffer;
+use ratatui::style::{Color, Modifier};
+use ratatui::text::Line;
+use ratatui::widgets::{BorderType, Widget};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Paragraph},
+};
+
+pub struct PlanLeavePanelComponent<'a> {
+    pub state: &'a PlanLeaveState,
+    pub translate: &'a Translate<'a>,
+}
+
+impl<'a> Widget for PlanLeavePanelComponent<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let outer_block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(format!(
+                " {} {} · {} ",
+                self.translate.plan_leave,
+                self.state.year,
+                self.translate
+                    .plan_leave_days
+                    .replace("{days}", &self.state.days.to_string())
+            ))
+            .title_alignment(Alignment::Center);
+
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let rows = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).split(inner_area);
+
+        if !self.state.has_data {
+            Paragraph::new(
+                self.translate
+                    .plan_leave_no_data
+                    .replace("{year}", &self.state.year.to_string()),
+            )
+            .alignment(Alignment::Center)
+            .render(rows[0], buf);
+        }
+
+        let lines: Vec<Line> = self
+            .state
+            .plans
+            .iter()
+            .enumerate()
+            .map(|(index, plan)| {
+                let leave_days = plan
+                    .leave_days
+                    .iter()
+                    .map(|date| date.format("%m-%d").to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let line = Line::from(format!(
+                    "{} ~ {}  {}  {}: {}",
+                    plan.start.format("%m-%d"),
+                    plan.end.format("%m-%d"),
+                    self.translate
+                        .plan_leave_days_off
+                        .replace("{days}", &plan.days_off().to_string()),
+                    self.translate.leave_day,
+                    leave_days
+                ));
+                if index == self.state.selected {
+                    line.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    line
+                }
+            })
+            .collect();
+        Paragraph::new(lines).render(rows[0], buf);
+
+        Paragraph::new(self.translate.plan_leave_help)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::DarkGray))
+            .render(rows[1], buf);
+    }
+}
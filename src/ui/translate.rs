@@ -1,3 +1,5 @@
+use crate::ui::japanese::japanese_era_year;
+use chrono::NaiveDate;
 use std::str::FromStr;
 
 pub fn get_month_til_i18n(year: i32, month: u32, lang: &str) -> String {
@@ -58,7 +60,14 @@ pub fn get_month_til_i18n(year: i32, month: u32, lang: &str) -> String {
             "Ноябрь",
             "Декабрь",
         ],
-        "ja" => return format!("{}年{}月", year, month), // 日语也用"年x月"
+        "ja" => {
+            // 日语在年份后附上和历年号，例如 "2026年（令和8年）10月"
+            let era_year = NaiveDate::from_ymd_opt(year, month, 1).and_then(japanese_era_year);
+            return match era_year {
+                Some(era_year) => format!("{}年（{}）{}月", year, era_year, month),
+                None => format!("{}年{}月", year, month),
+            };
+        }
         "ko" => return format!("{}년 {}월", year, month), // 韩语语法
         "zh" | "zh-TW" | "zh-HK" => return format!("{}年{}月", year, month),
         _ => return format!("{}-{}", year, month),
@@ -146,6 +155,34 @@ pub struct Translate<'a> {
     pub month: &'a str,
     pub day: &'a str,
     pub goto_help: &'a str,
+    pub almanac: &'a str,
+    pub almanac_help: &'a str,
+    pub almanac_recommends: &'a str,
+    pub almanac_avoids: &'a str,
+    pub almanac_clash: &'a str,
+    pub almanac_twelve_star: &'a str,
+    pub almanac_duty: &'a str,
+    pub almanac_directions: &'a str,
+    pub almanac_lucky_gods: &'a str,
+    pub almanac_unlucky_gods: &'a str,
+    pub holiday_load_failed: &'a str,
+    pub workdays: &'a str,
+    pub next_workday: &'a str,
+    pub measure: &'a str,
+    pub measure_help: &'a str,
+    /// 没有节假日数据 (或只有规则生成的数据) 的年份，{year} 会被替换为年份
+    pub no_holiday_data: &'a str,
+    /// {name} 和 {days} 会被替换为节日名称和天数
+    pub countdown: &'a str,
+    pub countdown_tomorrow: &'a str,
+    pub plan_leave: &'a str,
+    /// {days} 会被替换为天数
+    pub plan_leave_days: &'a str,
+    pub plan_leave_days_off: &'a str,
+    pub leave_day: &'a str,
+    pub plan_leave_help: &'a str,
+    /// {year} 会被替换为年份
+    pub plan_leave_no_data: &'a str,
 }
 
 const ZH_TRANSLATE: Translate<'static> = Translate {
@@ -160,6 +197,30 @@ const ZH_TRANSLATE: Translate<'static> = Translate {
     month: "月",
     day: "日",
     goto_help: "h,l:左右导航;j,k:加或减;enter:选择日期",
+    almanac: "黄历",
+    almanac_help: "h,j,k,l:切换日期;a,q:关闭",
+    almanac_recommends: "宜",
+    almanac_avoids: "忌",
+    almanac_clash: "冲煞",
+    almanac_twelve_star: "值神",
+    almanac_duty: "建除",
+    almanac_directions: "吉神方位",
+    almanac_lucky_gods: "吉神宜趋",
+    almanac_unlucky_gods: "凶神宜忌",
+    holiday_load_failed: "节假日数据下载失败，按 r 重试",
+    workdays: "工作日",
+    next_workday: "下一个工作日",
+    measure: "测量工作日",
+    measure_help: "[,]:上/下一个工作日;+,-:前/后第 N 个工作日;m,q:退出测量",
+    no_holiday_data: "{year} 年没有节假日数据，只按周末计算",
+    countdown: "{name}还有 {days} 天",
+    countdown_tomorrow: "明天就是{name}",
+    plan_leave: "请假规划",
+    plan_leave_days: "请假 {days} 天",
+    plan_leave_days_off: "休 {days} 天",
+    leave_day: "请假",
+    plan_leave_help: "j,k:选择方案;+,-:请假天数;p,q:关闭",
+    plan_leave_no_data: "{year} 年没有节假日数据，无法规划",
 };
 
 // Japanese (日本語)
@@ -175,6 +236,30 @@ const JA_TRANSLATE: Translate<'static> = Translate {
     month: "月",
     day: "日",
     goto_help: "h,l:左右移動;j,k:増減;enter:日付選択",
+    almanac: "暦注",
+    almanac_help: "h,j,k,l:日付移動;a,q:閉じる",
+    almanac_recommends: "吉事",
+    almanac_avoids: "凶事",
+    almanac_clash: "相冲",
+    almanac_twelve_star: "値神",
+    almanac_duty: "十二直",
+    almanac_directions: "吉神の方位",
+    almanac_lucky_gods: "吉神",
+    almanac_unlucky_gods: "凶神",
+    holiday_load_failed: "祝日データの取得に失敗しました。r で再試行",
+    workdays: "営業日",
+    next_workday: "次の営業日",
+    measure: "営業日計測",
+    measure_help: "[,]:前/次の営業日;+,-:N 営業日前/後;m,q:計測終了",
+    no_holiday_data: "{year} 年の祝日データがありません（週末のみで計算）",
+    countdown: "{name}まであと{days}日",
+    countdown_tomorrow: "明日は{name}",
+    plan_leave: "休暇プラン",
+    plan_leave_days: "休暇 {days} 日",
+    plan_leave_days_off: "{days} 連休",
+    leave_day: "休暇",
+    plan_leave_help: "j,k:プラン選択;+,-:休暇日数;p,q:閉じる",
+    plan_leave_no_data: "{year} 年の祝日データがないため計画できません",
 };
 
 // Korean (한국어)
@@ -190,6 +275,30 @@ const KO_TRANSLATE: Translate<'static> = Translate {
     month: "월",
     day: "일",
     goto_help: "h,l:좌우 이동;j,k:증감;enter:날짜 선택",
+    almanac: "택일력",
+    almanac_help: "h,j,k,l:날짜 이동;a,q:닫기",
+    almanac_recommends: "좋은 일",
+    almanac_avoids: "피할 일",
+    almanac_clash: "충살",
+    almanac_twelve_star: "치신",
+    almanac_duty: "건제",
+    almanac_directions: "길신 방위",
+    almanac_lucky_gods: "길신",
+    almanac_unlucky_gods: "흉신",
+    holiday_load_failed: "공휴일 데이터 다운로드 실패, r 키로 재시도",
+    workdays: "영업일",
+    next_workday: "다음 영업일",
+    measure: "영업일 측정",
+    measure_help: "[,]:이전/다음 영업일;+,-:N 영업일 전/후;m,q:측정 종료",
+    no_holiday_data: "{year}년 공휴일 데이터 없음 (주말만 계산)",
+    countdown: "{name}까지 {days}일",
+    countdown_tomorrow: "내일은 {name}",
+    plan_leave: "휴가 계획",
+    plan_leave_days: "휴가 {days}일",
+    plan_leave_days_off: "{days}일 연휴",
+    leave_day: "휴가",
+    plan_leave_help: "j,k:계획 선택;+,-:휴가 일수;p,q:닫기",
+    plan_leave_no_data: "{year}년 공휴일 데이터가 없어 계획할 수 없습니다",
 };
 
 // German (Deutsch)
@@ -205,6 +314,30 @@ const DE_TRANSLATE: Translate<'static> = Translate {
     month: "Monat",
     day: "Tag",
     goto_help: "h,l:Links/Rechts;j,k:+/-;Enter:Datum wählen",
+    almanac: "Almanach",
+    almanac_help: "h,j,k,l:Datum wechseln;a,q:Schließen",
+    almanac_recommends: "Günstig",
+    almanac_avoids: "Ungünstig",
+    almanac_clash: "Konflikt",
+    almanac_twelve_star: "Tagesgottheit",
+    almanac_duty: "Tageswächter",
+    almanac_directions: "Glücksrichtungen",
+    almanac_lucky_gods: "Glücksgötter",
+    almanac_unlucky_gods: "Unglücksgötter",
+    holiday_load_failed: "Feiertage konnten nicht geladen werden, r zum Wiederholen",
+    workdays: "Arbeitstage",
+    next_workday: "Nächster Arbeitstag",
+    measure: "Arbeitstage zählen",
+    measure_help: "[,]:Vorheriger/Nächster Arbeitstag;+,-:N Arbeitstage vor/zurück;m,q:Beenden",
+    no_holiday_data: "Keine Feiertagsdaten für {year}, nur Wochenenden gezählt",
+    countdown: "{name} in {days} Tagen",
+    countdown_tomorrow: "{name} ist morgen",
+    plan_leave: "Urlaubsplanung",
+    plan_leave_days: "{days} Urlaubstage",
+    plan_leave_days_off: "{days} Tage frei",
+    leave_day: "Urlaub",
+    plan_leave_help: "j,k:Plan wählen;+,-:Urlaubstage;p,q:Schließen",
+    plan_leave_no_data: "Keine Feiertagsdaten für {year}, keine Planung möglich",
 };
 
 // French (Français)
@@ -220,6 +353,30 @@ const FR_TRANSLATE: Translate<'static> = Translate {
     month: "Mois",
     day: "Jour",
     goto_help: "h,l:Gauche/Droite;j,k:+/-;Entrée:Sélectionner",
+    almanac: "Almanach",
+    almanac_help: "h,j,k,l:Changer de date;a,q:Fermer",
+    almanac_recommends: "Favorable",
+    almanac_avoids: "Défavorable",
+    almanac_clash: "Conflit",
+    almanac_twelve_star: "Divinité du jour",
+    almanac_duty: "Officier du jour",
+    almanac_directions: "Directions fastes",
+    almanac_lucky_gods: "Divinités fastes",
+    almanac_unlucky_gods: "Divinités néfastes",
+    holiday_load_failed: "Échec du téléchargement des jours fériés, r pour réessayer",
+    workdays: "Jours ouvrés",
+    next_workday: "Prochain jour ouvré",
+    measure: "Compter les jours ouvrés",
+    measure_help: "[,]:Jour ouvré précédent/suivant;+,-:N jours ouvrés après/avant;m,q:Quitter",
+    no_holiday_data: "Aucune donnée de jours fériés pour {year}, week-ends seulement",
+    countdown: "{name} dans {days} jours",
+    countdown_tomorrow: "{name} demain",
+    plan_leave: "Planifier des congés",
+    plan_leave_days: "{days} jours de congé",
+    plan_leave_days_off: "{days} jours libres",
+    leave_day: "Congé",
+    plan_leave_help: "j,k:Choisir;+,-:Jours de congé;p,q:Fermer",
+    plan_leave_no_data: "Aucune donnée de jours fériés pour {year}, planification impossible",
 };
 
 // Russian (Русский)
@@ -235,6 +392,30 @@ const RU_TRANSLATE: Translate<'static> = Translate {
     month: "Месяц",
     day: "День",
     goto_help: "h,l:Влево/Вправо;j,k:+/-;Enter:Выбрать",
+    almanac: "Альманах",
+    almanac_help: "h,j,k,l:Сменить дату;a,q:Закрыть",
+    almanac_recommends: "Благоприятно",
+    almanac_avoids: "Неблагоприятно",
+    almanac_clash: "Конфликт",
+    almanac_twelve_star: "Божество дня",
+    almanac_duty: "Страж дня",
+    almanac_directions: "Благие направления",
+    almanac_lucky_gods: "Благие божества",
+    almanac_unlucky_gods: "Злые божества",
+    holiday_load_failed: "Не удалось загрузить праздники, r — повторить",
+    workdays: "Рабочие дни",
+    next_workday: "Следующий рабочий день",
+    measure: "Подсчёт рабочих дней",
+    measure_help: "[,]:Пред./след. рабочий день;+,-:N рабочих дней вперёд/назад;m,q:Выход",
+    no_holiday_data: "Нет данных о праздниках за {year}, учтены только выходные",
+    countdown: "{name}: через {days} дн.",
+    countdown_tomorrow: "{name}: завтра",
+    plan_leave: "План отпуска",
+    plan_leave_days: "{days} дн. отпуска",
+    plan_leave_days_off: "{days} дн. отдыха",
+    leave_day: "Отпуск",
+    plan_leave_help: "j,k:Выбор;+,-:Дни отпуска;p,q:Закрыть",
+    plan_leave_no_data: "Нет данных о праздниках за {year}, планирование невозможно",
 };
 
 // English (English)
@@ -250,6 +431,30 @@ const EN_TRANSLATE: Translate<'static> = Translate {
     month: "Month",
     day: "Day",
     goto_help: "h,l:Navigate;j,k:+/-;Enter:Select",
+    almanac: "Almanac",
+    almanac_help: "h,j,k,l:Change date;a,q:Close",
+    almanac_recommends: "Good for",
+    almanac_avoids: "Avoid",
+    almanac_clash: "Clash",
+    almanac_twelve_star: "Day deity",
+    almanac_duty: "Day officer",
+    almanac_directions: "Lucky directions",
+    almanac_lucky_gods: "Lucky gods",
+    almanac_unlucky_gods: "Unlucky gods",
+    holiday_load_failed: "Failed to download holidays, press r to retry",
+    workdays: "Workdays",
+    next_workday: "Next workday",
+    measure: "Measure workdays",
+    measure_help: "[,]:Previous/next workday;+,-:N workdays after/before;m,q:Exit measure",
+    no_holiday_data: "No holiday data for {year}, only weekends counted",
+    countdown: "{name} in {days} days",
+    countdown_tomorrow: "{name} tomorrow",
+    plan_leave: "Plan leave",
+    plan_leave_days: "{days} leave days",
+    plan_leave_days_off: "{days} days off",
+    leave_day: "Leave",
+    plan_leave_help: "j,k:Select plan;+,-:Leave days;p,q:Close",
+    plan_leave_no_data: "No holiday data for {year}, cannot plan leave",
 };
 
 pub fn get_translate(language: Language) -> &'static Translate<'static> {
@@ -263,3 +468,16 @@ pub fn get_translate(language: Language) -> &'static Translate<'static> {
         Language::EN => &EN_TRANSLATE,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_month_til_i18n() {
+        assert_eq!(get_month_til_i18n(2026, 10, "en"), "October 2026");
+        assert_eq!(get_month_til_i18n(2026, 10, "zh"), "2026年10月");
+        assert_eq!(get_month_til_i18n(2026, 10, "ja"), "2026年（令和8年）10月");
+        assert_eq!(get_month_til_i18n(2019, 4, "ja"), "2019年（平成31年）4月");
+    }
+}
@@ -1,8 +1,4 @@
-use ratatui::{
-    buffer::Buffer,
-    layout::Rect,
-    widgets::{Widget, WidgetRef},
-};
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
 
 use super::layout::RiqiLayout;
 use crate::config::model::AppConfig;